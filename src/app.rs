@@ -1,91 +1,117 @@
-use crate::util::{StatefulList, TabsState};
-use inline_python::{python, Context};
+use crate::{
+    config::{Action, Config, KeyMap},
+    editor::LineEditor,
+    formula::{
+        attempt_formula,
+        bootstrap::{Bootstrap, BOOTSTRAP},
+        retrieve_aliases, retrieve_formula, TABS,
+    },
+    simulation::{Simulation, SIMULATION},
+    theme::Theme,
+    util::{data_dir, fuzzy_score, wrapped_height, StatefulList, TabsState},
+    variables::{result_fields, Variables, ANS},
+};
+
+use {
+    anyhow::Result,
+    inline_python::{python, Context},
+    std::{
+        collections::HashMap,
+        fs::{self, OpenOptions},
+        io::Write,
+        path::PathBuf,
+    },
+    termion::event::{Key, MouseButton, MouseEvent},
+    tui::layout::Rect,
+    unicode_width::UnicodeWidthStr,
+};
+
+const EXPORT_FILE: &str = "festats-export.txt";
 
 pub enum InputMode {
     Normal,
     Editing,
+    Storing,
+    Palette,
+    Help,
+}
+
+/// Inputs and last result a formula keeps for the rest of the session
+#[derive(Default)]
+pub struct FormulaState {
+    pub fields: Vec<LineEditor>,
+    pub focus: usize,
+    /// The last result and the input values it was computed from
+    pub result: Option<(Vec<String>, String)>,
+    /// Lines the output pane is scrolled down by
+    pub scroll: u16,
+    /// Index of the first input shown when they don't all fit
+    pub input_scroll: usize,
+}
+
+/// Where the last frame drew each clickable part of the screen
+#[derive(Default)]
+pub struct Areas {
+    pub tabs: Rect,
+    pub list: Rect,
+    /// Each visible input's index and area
+    pub inputs: Vec<(usize, Rect)>,
+    pub output: Rect,
+}
+
+fn contains(area: Rect, x: u16, y: u16) -> bool {
+    x >= area.x && x < area.x + area.width && y >= area.y && y < area.y + area.height
 }
 
 pub struct App<'a> {
     items: Vec<StatefulList<&'a str>>,
-    pub input: Vec<(usize, Vec<String>)>,
+    pub input: HashMap<&'a str, FormulaState>,
     pub title: &'a str,
     pub input_mode: InputMode,
-    pub entered_inputs: Vec<Vec<String>>,
     pub should_quit: bool,
     pub tabs: TabsState<'a>,
     pub python: Context,
+    pub variables: Variables,
+    pub store_name: String,
+    pub store_field: usize,
+    pub kill_buffer: String,
+    pub palette: LineEditor,
+    pub palette_selected: usize,
+    pub keys: KeyMap,
+    pub theme: Theme,
+    pub status: String,
+    pub areas: Areas,
+    /// The last sampling distribution started, kept until another one replaces it
+    pub simulation: Option<Simulation>,
+    /// Resampling still in progress, with the inputs it was started from
+    pub bootstrap: Option<(Vec<String>, Bootstrap)>,
 }
 
 impl<'a> App<'a> {
-    pub fn new(title: &'a str) -> App<'a> {
+    pub fn new(title: &'a str, config: Config) -> App<'a> {
         App {
             title,
-            input: vec![
-                (0, vec![String::new()]),
-                (0, vec![String::new()]),
-                (0, vec![String::new()]),
-                (0, vec![String::new()]),
-            ],
+            input: HashMap::new(),
             input_mode: InputMode::Normal,
-            entered_inputs: vec![Vec::new(), Vec::new(), Vec::new(), Vec::new()],
             should_quit: false,
-            tabs: TabsState::new(vec!["Probability", "Intervals", "Tests", "Regressions"]),
-            items: vec![
-                StatefulList::with_items(vec![
-                    "Factorial (!)",
-                    "Permutations",
-                    "Combinations",
-                    "Normal Pdf",
-                    "Normal Cdf",
-                    "Inverse Normal",
-                    "t Pdf",
-                    "t Cdf",
-                    // TODO: Add this in the future, not that easy to do
-                    // "Inverse t",
-                    "χ2 Pdf",
-                    "χ2 Cdf",
-                    // TODO: Add this in the future, not that easy to do
-                    // "Inverse χ2",
-                    "Binomial Pdf",
-                    "Binomial Cdf",
-                    "F Pdf",
-                    "F Cdf",
-                    "Geometric Pdf",
-                    "Geometric Cdf",
-                    "Poisson Pdf",
-                    "Poisson Cdf",
-                ]),
-                StatefulList::with_items(vec![
-                    "z Interval",
-                    "t Interval",
-                    "2-Sample z Interval",
-                    "2-Sample t Interval",
-                    "1-Prop z Interval",
-                    "2-Prop z Interval",
-                ]),
-                StatefulList::with_items(vec![
-                    "z Test",
-                    "t Test",
-                    "2-Sample z Test",
-                    "2-Sample t Test",
-                    "1-Prop z Test",
-                    "2-Prop z Test",
-                    "χ2 GOF",
-                    "χ2 2-way Test",
-                    "2-Sample F Test",
-                    "ANOVA",
-                ]),
-                StatefulList::with_items(vec![
-                    "Linear Regression (mx+b)",
-                    "Linear Regression (a+bx)",
-                    "Median-Median Line",
-                    "Quadratic Regression",
-                    "Cubic Regression",
-                    "Quartic Regression",
-                ]),
-            ],
+            tabs: TabsState::new(TABS.iter().map(|(title, _)| *title).collect()),
+            items: TABS
+                .iter()
+                .map(|(_, formulas)| StatefulList::with_items(formulas.to_vec()))
+                .collect(),
             python: Context::new(),
+            variables: Variables::load(),
+            store_name: String::new(),
+            store_field: 0,
+            kill_buffer: String::new(),
+            palette: LineEditor::new(),
+            palette_selected: 0,
+            keys: config.keys,
+            theme: config.theme,
+            status: String::new(),
+            areas: Areas::default(),
+            simulation: None,
+            bootstrap: None,
         }
     }
 
@@ -94,64 +120,241 @@ impl<'a> App<'a> {
     }
 
     pub fn position(&mut self, position: &str) {
-        let current_input = self.current_input();
-        current_input.0 = 0;
-        current_input.1.drain(..);
-        current_input.1.push(String::new());
-        self.current_stored_input().drain(..);
-
-        let i = match position {
-            "next" => match self.items[self.tabs.index].state.selected() {
-                Some(i) => {
-                    if i >= self.items[self.tabs.index].items.len() - 1 {
-                        0
-                    } else {
-                        i + 1
-                    }
-                }
-                None => 0,
-            },
-            "previous" => match self.items[self.tabs.index].state.selected() {
-                Some(i) => {
-                    if i == 0 {
-                        self.items[self.tabs.index].items.len() - 1
-                    } else {
-                        i - 1
-                    }
-                }
-                None => 0,
-            },
-            _ => unreachable!(),
-        };
-
-        self.items[self.tabs.index].state.select(Some(i));
+        self.current_items().position(position);
     }
 
     pub fn current_title(&mut self) -> &str {
         self.tabs.titles[self.tabs.index]
     }
 
-    pub fn current_input(&mut self) -> &mut (usize, Vec<String>) {
-        &mut self.input[self.tabs.index]
+    pub fn current_state(&mut self) -> &mut FormulaState {
+        let formula_name = *self.current_items().current_item();
+        self.input.entry(formula_name).or_default()
+    }
+
+    pub fn current_input(&mut self) -> &mut Vec<LineEditor> {
+        &mut self.current_state().fields
+    }
+
+    pub fn current_input_field(&mut self, index: usize) -> &mut LineEditor {
+        let fields = self.current_input();
+        while fields.len() <= index {
+            fields.push(LineEditor::new());
+        }
+        &mut fields[index]
+    }
+
+    /// Index of the input field that has focus
+    pub fn current_focus(&mut self) -> &mut usize {
+        &mut self.current_state().focus
+    }
+
+    pub fn focus_next(&mut self) {
+        let formula_name = *self.current_items().current_item();
+        let count = retrieve_formula(formula_name).len();
+        let focus = self.current_focus();
+        *focus = (*focus + 1) % count;
+    }
+
+    pub fn focus_previous(&mut self) {
+        let formula_name = *self.current_items().current_item();
+        let count = retrieve_formula(formula_name).len();
+        let focus = self.current_focus();
+        *focus = (*focus + count - 1) % count;
+    }
+
+    pub fn is_last_field_focused(&mut self) -> bool {
+        let formula_name = *self.current_items().current_item();
+        let count = retrieve_formula(formula_name).len();
+        *self.current_focus() + 1 >= count
+    }
+
+    /// Applies a line editing key to the focused input field
+    pub fn edit_input(&mut self, key: Key) {
+        let index = *self.current_focus();
+        let mut kill_buffer = self.kill_buffer.to_owned();
+        let field = self.current_input_field(index);
+
+        field.edit(key, &mut kill_buffer);
+        self.kill_buffer = kill_buffer;
+    }
+
+    /// Text of every input field of the current formula, with variables replaced by their values
+    pub fn current_field_values(&mut self) -> Vec<String> {
+        let formula_name = *self.current_items().current_item();
+        let count = retrieve_formula(formula_name).len();
+
+        (0..count)
+            .map(|index| {
+                let text = self.current_input_field(index).text().to_owned();
+                self.variables.resolve(&text)
+            })
+            .collect()
+    }
+
+    /// The current formula's result, only recomputed when its inputs have changed
+    pub fn current_output(&mut self) -> Result<String> {
+        let formula_name = *self.current_items().current_item();
+        let values = self.current_field_values();
+
+        if let Some((inputs, output)) = &self.current_state().result {
+            if *inputs == values {
+                return Ok(output.to_owned());
+            }
+        }
+
+        let output = attempt_formula(self, formula_name, &values)?;
+        self.current_state().result = Some((values, output.to_owned()));
+        Ok(output)
     }
 
-    pub fn current_input_text(&mut self, index: usize) -> &mut String {
-        if self.input[self.tabs.index].1.len() - 1 < index {
-            self.input[self.tabs.index].1.push(String::new());
+    /// Records the current result in `Ans`, or starts the simulation
+    pub fn submit(&mut self) -> Result<()> {
+        if *self.current_items().current_item() == SIMULATION {
+            let values = self.current_field_values();
+            if let Ok(simulation) = Simulation::from_inputs(&values) {
+                self.simulation = Some(simulation);
+            }
+            return Ok(());
+        }
+        if *self.current_items().current_item() == BOOTSTRAP {
+            let values = self.current_field_values();
+            if let Ok(bootstrap) = Bootstrap::from_inputs(&values) {
+                self.keep_ans_references();
+                self.bootstrap = Some((values, bootstrap));
+            }
+            return Ok(());
         }
-        &mut self.input[self.tabs.index].1[index]
+
+        let output = self.current_output()?;
+
+        if let Some((_, value)) = result_fields(&output).into_iter().next() {
+            self.keep_ans_references();
+            self.variables.set_ans(value)?;
+        }
+        Ok(())
     }
 
-    pub fn current_stored_input(&mut self) -> &mut Vec<String> {
-        &mut self.entered_inputs[self.tabs.index]
+    /// Inputs that use Ans keep the value they were computed with rather than picking up
+    /// the result they are about to produce
+    fn keep_ans_references(&mut self) {
+        for index in 0..self.current_input().len() {
+            let text = self.current_input_field(index).text().to_owned();
+            if self.variables.references(&text, ANS) {
+                let resolved = self.variables.resolve(&text);
+                self.current_input_field(index).set_text(&resolved);
+            }
+        }
     }
 
-    pub fn current_input_text_ref(&mut self) -> String {
-        self.input[self.tabs.index].1.join(" ")
+    /// Whether the simulation on screen still has samples to draw, so ticks are needed
+    pub fn is_simulating(&mut self) -> bool {
+        let selected = *self.current_items().current_item() == SIMULATION;
+        match &self.simulation {
+            Some(simulation) => selected && !simulation.is_finished(),
+            None => false,
+        }
     }
 
-    pub fn current_stored_input_ref(&mut self) -> &Vec<String> {
-        &self.entered_inputs[self.tabs.index]
+    /// Whether anything is animating or running in the background, so ticks are needed
+    pub fn needs_ticks(&mut self) -> bool {
+        self.is_simulating() || self.bootstrap.is_some()
+    }
+
+    /// Advances the simulation and the resampling by one tick each, returning whether there
+    /// is anything new to draw. Finished resampling becomes the Bootstrap Interval's result.
+    pub fn on_tick(&mut self) -> Result<bool> {
+        let mut redraw = false;
+
+        if self.is_simulating() {
+            if let Some(simulation) = &mut self.simulation {
+                simulation.step();
+            }
+            redraw = true;
+        }
+
+        if let Some((_, bootstrap)) = &mut self.bootstrap {
+            bootstrap.step();
+            redraw = true;
+
+            if bootstrap.is_finished() {
+                let (values, bootstrap) = self.bootstrap.take().unwrap();
+                let output = bootstrap.result();
+                if let Some((_, value)) = result_fields(&output).into_iter().next() {
+                    self.variables.set_ans(value)?;
+                }
+                self.input.entry(BOOTSTRAP).or_default().result = Some((values, output));
+            }
+        }
+
+        Ok(redraw)
+    }
+
+    /// Every formula on every tab matching the palette query as (tab, item, name), best first
+    pub fn palette_matches(&self) -> Vec<(usize, usize, &'a str)> {
+        let query = self.palette.text();
+        let mut matches: Vec<(i64, usize, usize, &'a str)> = self
+            .items
+            .iter()
+            .enumerate()
+            .flat_map(|(tab, list)| {
+                list.items
+                    .iter()
+                    .enumerate()
+                    .map(move |(item, name)| (tab, item, *name))
+            })
+            .filter_map(|(tab, item, name)| {
+                std::iter::once(name)
+                    .chain(retrieve_aliases(name))
+                    .filter_map(|candidate| fuzzy_score(query, candidate))
+                    .max()
+                    .map(|score| (score, tab, item, name))
+            })
+            .collect();
+
+        if !query.trim().is_empty() {
+            matches.sort_by_key(|m| std::cmp::Reverse(m.0));
+        }
+        matches
+            .into_iter()
+            .map(|(_, tab, item, name)| (tab, item, name))
+            .collect()
+    }
+
+    pub fn open_palette(&mut self) {
+        self.palette = LineEditor::new();
+        self.palette_selected = 0;
+        self.input_mode = InputMode::Palette;
+    }
+
+    pub fn palette_next(&mut self) {
+        let count = self.palette_matches().len().max(1);
+        self.palette_selected = (self.palette_selected + 1) % count;
+    }
+
+    pub fn palette_previous(&mut self) {
+        let count = self.palette_matches().len().max(1);
+        self.palette_selected = (self.palette_selected + count - 1) % count;
+    }
+
+    pub fn edit_palette(&mut self, key: Key) {
+        let mut kill_buffer = self.kill_buffer.to_owned();
+        self.palette.edit(key, &mut kill_buffer);
+        self.kill_buffer = kill_buffer;
+        self.palette_selected = 0;
+    }
+
+    /// Jumps to the selected palette entry, returning whether there was one
+    pub fn palette_jump(&mut self) -> bool {
+        match self.palette_matches().get(self.palette_selected) {
+            Some(&(tab, item, _)) => {
+                self.tabs.set_index(tab);
+                self.items[tab].state.select(Some(item));
+                *self.current_focus() = 0;
+                true
+            }
+            None => false,
+        }
     }
 
     pub fn on_right(&mut self) {
@@ -162,24 +365,137 @@ impl<'a> App<'a> {
         self.tabs.previous();
     }
 
-    pub fn on_key(&mut self, c: char) {
-        match c {
-            'q' => {
-                self.should_quit = true;
-            }
-            'p' => {
-                self.tabs.set_index(0);
+    /// Handles the Normal mode actions that don't change the input mode
+    pub fn on_action(&mut self, action: Action) -> Result<()> {
+        match action {
+            Action::NextFormula => self.position("next"),
+            Action::PreviousFormula => self.position("previous"),
+            Action::NextTab => self.on_right(),
+            Action::PreviousTab => self.on_left(),
+            Action::ProbabilityTab => self.tabs.set_index(0),
+            Action::RandomTab => self.tabs.set_index(1),
+            Action::IntervalsTab => self.tabs.set_index(2),
+            Action::TestsTab => self.tabs.set_index(3),
+            Action::RegressionsTab => self.tabs.set_index(4),
+            Action::Export => {
+                // A read-only data dir shouldn't end the session, the status bar says what failed
+                if let Err(err) = self.export() {
+                    self.status = format!("Couldn't export: {}", err);
+                }
             }
-            'i' => {
-                self.tabs.set_index(1);
+            Action::Quit => self.should_quit = true,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn on_mouse(&mut self, event: MouseEvent) {
+        // termion's coordinates start at 1, tui's at 0
+        let (button, x, y) = match event {
+            MouseEvent::Press(button, x, y) => (button, x.saturating_sub(1), y.saturating_sub(1)),
+            _ => return,
+        };
+
+        match button {
+            MouseButton::Left => {
+                if contains(self.areas.tabs, x, y) {
+                    if let Some(tab) = self.tab_at(x, y) {
+                        self.tabs.set_index(tab);
+                        self.input_mode = InputMode::Normal;
+                    }
+                } else if contains(self.areas.list, x, y) && y > self.areas.list.y {
+                    let row = (y - self.areas.list.y - 1) as usize;
+                    let list = self.current_items();
+                    let index = list.offset + row;
+                    if index < list.items.len() {
+                        list.state.select(Some(index));
+                        self.input_mode = InputMode::Normal;
+                    }
+                } else if let Some(&(index, _)) = self
+                    .areas
+                    .inputs
+                    .iter()
+                    .find(|(_, area)| contains(*area, x, y))
+                {
+                    *self.current_focus() = index;
+                    self.input_mode = InputMode::Editing;
+                }
             }
-            't' => {
-                self.tabs.set_index(2);
+            MouseButton::WheelUp => {
+                if contains(self.areas.list, x, y) {
+                    self.position("previous");
+                } else if contains(self.areas.output, x, y) {
+                    let state = self.current_state();
+                    state.scroll = state.scroll.saturating_sub(1);
+                }
             }
-            'r' => {
-                self.tabs.set_index(3);
+            MouseButton::WheelDown => {
+                if contains(self.areas.list, x, y) {
+                    self.position("next");
+                } else if contains(self.areas.output, x, y) {
+                    let max_scroll = self.max_output_scroll();
+                    let state = self.current_state();
+                    state.scroll = (state.scroll + 1).min(max_scroll);
+                }
             }
             _ => {}
         }
     }
+
+    /// How far the output pane scrolls before its last line is at the bottom of the pane
+    fn max_output_scroll(&mut self) -> u16 {
+        // Inside the pane's borders
+        let width = self.areas.output.width.saturating_sub(2);
+        let height = self.areas.output.height.saturating_sub(2);
+        let output = self.current_output().unwrap_or_default();
+        wrapped_height(&output, width).saturating_sub(height)
+    }
+
+    /// The tab whose title is drawn at the given position, mirroring how `Tabs` lays them out
+    fn tab_at(&self, x: u16, y: u16) -> Option<usize> {
+        if y != self.areas.tabs.y + 1 {
+            return None;
+        }
+        // Skip the border and the padding in front of the first title
+        let mut start = self.areas.tabs.x + 2;
+        for (i, title) in self.tabs.titles.iter().enumerate() {
+            let end = start + title.width() as u16;
+            if x + 1 >= start && x <= end {
+                return Some(i);
+            }
+            // Padding, divider, padding
+            start = end + 3;
+        }
+        None
+    }
+
+    /// Appends the current formula, its inputs and its result to the export file in the data
+    /// dir, or the working directory when there is no data dir
+    pub fn export(&mut self) -> Result<()> {
+        let formula_name = *self.current_items().current_item();
+        let labels = retrieve_formula(formula_name);
+        let values = self.current_field_values();
+        let output = self.current_output()?;
+
+        let mut contents = format!("{}\n", formula_name);
+        for (label, value) in labels.iter().zip(values.iter()) {
+            contents.push_str(&format!("  {}: {}\n", label, value));
+        }
+        for line in output.lines() {
+            contents.push_str(&format!("  = {}\n", line));
+        }
+        contents.push('\n');
+
+        let path = match data_dir() {
+            Some(dir) => {
+                fs::create_dir_all(&dir)?;
+                dir.join(EXPORT_FILE)
+            }
+            None => PathBuf::from(EXPORT_FILE),
+        };
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        file.write_all(contents.as_bytes())?;
+        self.status = format!("Exported {} to {}", formula_name, path.display());
+        Ok(())
+    }
 }
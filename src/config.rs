@@ -0,0 +1,506 @@
+use crate::{
+    editor::EDITING_KEYS,
+    theme::{Theme, THEMES},
+};
+
+use {
+    anyhow::{anyhow, bail, Context as _, Result},
+    serde::Deserialize,
+    std::{collections::BTreeMap, env, fs, path::PathBuf},
+    termion::event::Key,
+};
+
+/// Everything a key can be bound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    NextFormula,
+    PreviousFormula,
+    NextTab,
+    PreviousTab,
+    ProbabilityTab,
+    RandomTab,
+    IntervalsTab,
+    TestsTab,
+    RegressionsTab,
+    Edit,
+    Store,
+    Export,
+    Palette,
+    Help,
+    Suspend,
+    Quit,
+    Submit,
+    Cancel,
+    NextField,
+    PreviousField,
+}
+
+/// Which input mode an action is looked up in. Keys only conflict within the same mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Normal,
+    Editing,
+}
+
+impl Action {
+    pub const ALL: [Action; 20] = [
+        Action::NextFormula,
+        Action::PreviousFormula,
+        Action::NextTab,
+        Action::PreviousTab,
+        Action::ProbabilityTab,
+        Action::RandomTab,
+        Action::IntervalsTab,
+        Action::TestsTab,
+        Action::RegressionsTab,
+        Action::Edit,
+        Action::Store,
+        Action::Export,
+        Action::Palette,
+        Action::Help,
+        Action::Suspend,
+        Action::Quit,
+        Action::Submit,
+        Action::Cancel,
+        Action::NextField,
+        Action::PreviousField,
+    ];
+
+    /// Name of the action in the config file
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::NextFormula => "next_formula",
+            Action::PreviousFormula => "previous_formula",
+            Action::NextTab => "next_tab",
+            Action::PreviousTab => "previous_tab",
+            Action::ProbabilityTab => "probability_tab",
+            Action::RandomTab => "random_tab",
+            Action::IntervalsTab => "intervals_tab",
+            Action::TestsTab => "tests_tab",
+            Action::RegressionsTab => "regressions_tab",
+            Action::Edit => "edit",
+            Action::Store => "store",
+            Action::Export => "export",
+            Action::Palette => "palette",
+            Action::Help => "help",
+            Action::Suspend => "suspend",
+            Action::Quit => "quit",
+            Action::Submit => "submit",
+            Action::Cancel => "cancel",
+            Action::NextField => "next_field",
+            Action::PreviousField => "previous_field",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Action::NextFormula => "Select the next formula",
+            Action::PreviousFormula => "Select the previous formula",
+            Action::NextTab => "Switch to the next tab",
+            Action::PreviousTab => "Switch to the previous tab",
+            Action::ProbabilityTab => "Switch to the Probability tab",
+            Action::RandomTab => "Switch to the Random tab",
+            Action::IntervalsTab => "Switch to the Intervals tab",
+            Action::TestsTab => "Switch to the Tests tab",
+            Action::RegressionsTab => "Switch to the Regressions tab",
+            Action::Edit => "Start editing the formula's inputs",
+            Action::Store => "Store a result field in a variable",
+            Action::Export => "Export the formula, inputs and result",
+            Action::Palette => "Search every formula",
+            Action::Help => "Show this help",
+            Action::Suspend => "Suspend to the shell, resume with fg",
+            Action::Quit => "Quit FeStats",
+            Action::Submit => "Next input, or finish on the last one",
+            Action::Cancel => "Stop editing",
+            Action::NextField => "Focus the next input",
+            Action::PreviousField => "Focus the previous input",
+        }
+    }
+
+    pub fn mode(self) -> Mode {
+        match self {
+            Action::Submit | Action::Cancel | Action::NextField | Action::PreviousField => {
+                Mode::Editing
+            }
+            _ => Mode::Normal,
+        }
+    }
+
+    fn default_keys(self) -> Vec<Key> {
+        match self {
+            Action::NextFormula => vec![Key::Char('j'), Key::Down],
+            Action::PreviousFormula => vec![Key::Char('k'), Key::Up],
+            Action::NextTab => vec![Key::Char('l'), Key::Right],
+            Action::PreviousTab => vec![Key::Char('h'), Key::Left],
+            Action::ProbabilityTab => vec![Key::Char('p')],
+            // Lowercase r already belongs to Regressions
+            Action::RandomTab => vec![Key::Char('R')],
+            Action::IntervalsTab => vec![Key::Char('i')],
+            Action::TestsTab => vec![Key::Char('t')],
+            Action::RegressionsTab => vec![Key::Char('r')],
+            Action::Edit => vec![Key::Char('\n')],
+            Action::Store => vec![Key::Char('s')],
+            Action::Export => vec![Key::Char('e')],
+            Action::Palette => vec![Key::Char('/'), Key::Char(':')],
+            Action::Help => vec![Key::Char('?')],
+            Action::Suspend => vec![Key::Ctrl('z')],
+            Action::Quit => vec![Key::Char('q')],
+            Action::Submit => vec![Key::Char('\n')],
+            Action::Cancel => vec![Key::Esc],
+            Action::NextField => vec![Key::Char('\t'), Key::Down],
+            Action::PreviousField => vec![Key::BackTab, Key::Up],
+        }
+    }
+}
+
+/// Key bindings for every action, checked so that no key is bound twice in the same mode
+pub struct KeyMap {
+    bindings: Vec<(Action, Vec<Key>)>,
+}
+
+impl Default for KeyMap {
+    fn default() -> KeyMap {
+        KeyMap {
+            bindings: Action::ALL
+                .iter()
+                .map(|action| (*action, action.default_keys()))
+                .collect(),
+        }
+    }
+}
+
+impl KeyMap {
+    pub fn action(&self, key: Key, mode: Mode) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(action, keys)| action.mode() == mode && keys.contains(&key))
+            .map(|(action, _)| *action)
+    }
+
+    pub fn keys(&self, action: Action) -> &[Key] {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| *bound == action)
+            .map(|(_, keys)| keys.as_slice())
+            .unwrap_or(&[])
+    }
+
+    fn bind(&mut self, action: Action, keys: Vec<Key>) {
+        if let Some(binding) = self.bindings.iter_mut().find(|(bound, _)| *bound == action) {
+            binding.1 = keys;
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.keys(Action::Quit).is_empty() {
+            bail!("`quit` has to be bound to at least one key");
+        }
+        for (i, (action, keys)) in self.bindings.iter().enumerate() {
+            for key in keys {
+                if action.mode() == Mode::Editing {
+                    if let Key::Char(c) = key {
+                        if !c.is_control() {
+                            bail!(
+                                "`{}` can't be bound to `{}`, it would stop that character being typed into inputs",
+                                action.name(),
+                                key_name(*key)
+                            );
+                        }
+                    }
+                    if EDITING_KEYS.iter().any(|(keys, _)| keys.contains(key)) {
+                        bail!(
+                            "`{}` can't be bound to `{}`, the input editor already uses it",
+                            action.name(),
+                            key_name(*key)
+                        );
+                    }
+                }
+                for (other, other_keys) in &self.bindings[i + 1..] {
+                    if other.mode() == action.mode() && other_keys.contains(key) {
+                        bail!(
+                            "`{}` is bound to both `{}` and `{}`",
+                            key_name(*key),
+                            action.name(),
+                            other.name()
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct ConfigFile {
+    keys: BTreeMap<String, Vec<String>>,
+    theme: Option<String>,
+}
+
+pub struct Config {
+    pub keys: KeyMap,
+    pub theme: Theme,
+}
+
+impl Config {
+    /// Reads `config.toml` from the XDG config dir, falling back to the defaults if there is none
+    pub fn load() -> Result<Config> {
+        let path = match config_path() {
+            Some(path) if path.exists() => path,
+            _ => {
+                return Ok(Config {
+                    keys: KeyMap::default(),
+                    theme: default_theme(),
+                })
+            }
+        };
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Couldn't read config file {}", path.display()))?;
+        Config::parse(&contents).with_context(|| format!("Invalid config file {}", path.display()))
+    }
+
+    fn parse(contents: &str) -> Result<Config> {
+        let file: ConfigFile = toml::from_str(contents)?;
+        let mut keys = KeyMap::default();
+
+        for (name, key_names) in file.keys {
+            let action = Action::ALL
+                .iter()
+                .find(|action| action.name() == name)
+                .ok_or_else(|| anyhow!("Unknown action `{}` in [keys]", name))?;
+            let bound = key_names
+                .iter()
+                .map(|key| parse_key(key))
+                .collect::<Result<Vec<Key>>>()
+                .with_context(|| format!("Invalid key for `{}`", name))?;
+            keys.bind(*action, bound);
+        }
+
+        keys.validate()?;
+
+        let theme = match file.theme {
+            Some(name) => named_theme(&name)?,
+            None => default_theme(),
+        };
+
+        Ok(Config { keys, theme })
+    }
+
+    /// Applies command line flags, which take precedence over the config file
+    pub fn apply_args<I>(&mut self, mut args: I) -> Result<()>
+    where
+        I: Iterator<Item = String>,
+    {
+        while let Some(arg) = args.next() {
+            if arg == "--theme" {
+                let name = args
+                    .next()
+                    .ok_or_else(|| anyhow!("`--theme` needs a theme name"))?;
+                self.theme = named_theme(&name)?;
+            } else if let Some(name) = arg.strip_prefix("--theme=") {
+                self.theme = named_theme(name)?;
+            } else {
+                bail!(
+                    "Unknown argument `{}`, the only option is --theme <{}>",
+                    arg,
+                    THEMES.join("|")
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+fn named_theme(name: &str) -> Result<Theme> {
+    Theme::named(name).ok_or_else(|| {
+        anyhow!(
+            "Unknown theme `{}`, expected one of {}",
+            name,
+            THEMES.join(", ")
+        )
+    })
+}
+
+/// Honors NO_COLOR (https://no-color.org) unless a theme was chosen explicitly
+fn default_theme() -> Theme {
+    match env::var_os("NO_COLOR") {
+        Some(value) if !value.is_empty() => Theme::monochrome(),
+        _ => Theme::default(),
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    let dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+    Some(dir.join("festats").join("config.toml"))
+}
+
+/// Parses a key such as `j`, `ctrl-a`, `alt-f`, `enter`, `shift-tab` or `f5`
+pub fn parse_key(name: &str) -> Result<Key> {
+    let lower = name.to_lowercase();
+    let single = |rest: &str| {
+        let mut chars = rest.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(anyhow!("`{}` isn't a key", name)),
+        }
+    };
+
+    let key = match lower.as_str() {
+        "enter" | "return" => Key::Char('\n'),
+        "tab" => Key::Char('\t'),
+        "backtab" | "shift-tab" => Key::BackTab,
+        "esc" | "escape" => Key::Esc,
+        "space" => Key::Char(' '),
+        "backspace" => Key::Backspace,
+        "delete" | "del" => Key::Delete,
+        "insert" => Key::Insert,
+        "up" => Key::Up,
+        "down" => Key::Down,
+        "left" => Key::Left,
+        "right" => Key::Right,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp,
+        "pagedown" => Key::PageDown,
+        _ => {
+            if let Some(rest) = lower.strip_prefix("ctrl-") {
+                Key::Ctrl(single(rest)?)
+            } else if let Some(rest) = lower.strip_prefix("alt-") {
+                Key::Alt(single(rest)?)
+            } else if let Some(number) = lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok())
+            {
+                Key::F(number)
+            } else {
+                // Plain characters keep their case, `J` and `j` are different keys
+                Key::Char(single(name)?)
+            }
+        }
+    };
+    Ok(key)
+}
+
+/// Human readable name of a key, in the same format `parse_key` accepts
+pub fn key_name(key: Key) -> String {
+    match key {
+        Key::Char('\n') => String::from("enter"),
+        Key::Char('\t') => String::from("tab"),
+        Key::Char(' ') => String::from("space"),
+        Key::Char(c) => c.to_string(),
+        Key::Ctrl(c) => format!("ctrl-{}", c),
+        Key::Alt(c) => format!("alt-{}", c),
+        Key::F(n) => format!("f{}", n),
+        Key::BackTab => String::from("shift-tab"),
+        Key::Esc => String::from("esc"),
+        Key::Backspace => String::from("backspace"),
+        Key::Delete => String::from("delete"),
+        Key::Insert => String::from("insert"),
+        Key::Up => String::from("up"),
+        Key::Down => String::from("down"),
+        Key::Left => String::from("left"),
+        Key::Right => String::from("right"),
+        Key::Home => String::from("home"),
+        Key::End => String::from("end"),
+        Key::PageUp => String::from("pageup"),
+        Key::PageDown => String::from("pagedown"),
+        _ => String::from("?"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The whole chain of an error from `Config::parse`, context included
+    fn parse_error(contents: &str) -> String {
+        match Config::parse(contents) {
+            Ok(_) => panic!("{} should have been rejected", contents),
+            Err(err) => format!("{:#}", err),
+        }
+    }
+
+    #[test]
+    fn keys_round_trip_through_their_names() {
+        let keys = [
+            Key::Char('j'),
+            Key::Char('J'),
+            Key::Char('\n'),
+            Key::Char('\t'),
+            Key::Char(' '),
+            Key::Ctrl('a'),
+            Key::Alt('f'),
+            Key::F(5),
+            Key::BackTab,
+            Key::Esc,
+            Key::Backspace,
+            Key::PageDown,
+        ];
+        for key in keys.iter() {
+            assert_eq!(parse_key(&key_name(*key)).unwrap(), *key);
+        }
+
+        assert_eq!(parse_key("Return").unwrap(), Key::Char('\n'));
+        assert_eq!(parse_key("CTRL-N").unwrap(), Key::Ctrl('n'));
+        assert!(parse_key("ctrl-ab").is_err());
+        assert!(parse_key("jk").is_err());
+        assert!(parse_key("").is_err());
+    }
+
+    #[test]
+    fn a_config_rebinds_only_its_actions() {
+        let config = Config::parse(
+            r#"
+            theme = "monochrome"
+
+            [keys]
+            next_formula = ["n", "down"]
+            quit = ["ctrl-q", "Q"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.keys.keys(Action::NextFormula),
+            &[Key::Char('n'), Key::Down]
+        );
+        assert_eq!(
+            config.keys.action(Key::Char('Q'), Mode::Normal),
+            Some(Action::Quit)
+        );
+        assert_eq!(config.keys.action(Key::Char('j'), Mode::Normal), None);
+        // Everything else keeps its default
+        assert_eq!(config.keys.keys(Action::Edit), &[Key::Char('\n')]);
+        assert!(Config::parse("").is_ok());
+    }
+
+    #[test]
+    fn bad_configs_are_explained() {
+        assert!(parse_error("[keys]\njump = [\"g\"]").contains("Unknown action `jump`"));
+        assert!(parse_error("[keys]\nquit = [\"hyper-q\"]").contains("Invalid key for `quit`"));
+        assert!(parse_error("[keys]\nquit = []").contains("`quit` has to be bound"));
+        assert!(parse_error("theme = \"neon\"").contains("Unknown theme `neon`"));
+        assert!(parse_error("colour = \"red\"").contains("unknown field"));
+    }
+
+    #[test]
+    fn conflicting_bindings_are_rejected() {
+        assert!(parse_error("[keys]\nexport = [\"q\"]").contains("`q` is bound to both"));
+        // Enter both edits and submits by default, keys only conflict within a mode
+        assert!(Config::parse("[keys]\nnext_field = [\"ctrl-n\"]").is_ok());
+        // Editing actions can't take a character that should be typed
+        assert!(parse_error("[keys]\nnext_field = [\"j\"]").contains("typed into inputs"));
+
+        for key in ["ctrl-a", "ctrl-e", "left", "right", "backspace", "ctrl-w"].iter() {
+            let error = parse_error(&format!("[keys]\ncancel = [\"{}\"]", key));
+            assert!(
+                error.contains("the input editor already uses it"),
+                "{}",
+                error
+            );
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use {termion::event::Key, unicode_width::UnicodeWidthStr};
+
+/// The keys `LineEditor::edit` understands besides plain characters, for the help screen and
+/// so that the config can't bind an editing action over one of them
+pub const EDITING_KEYS: [(&[Key], &str); 12] = [
+    (&[Key::Left, Key::Ctrl('b')], "Move the cursor back"),
+    (&[Key::Right, Key::Ctrl('f')], "Move the cursor forward"),
+    (&[Key::Home, Key::Ctrl('a')], "Go to the start of the input"),
+    (&[Key::End, Key::Ctrl('e')], "Go to the end of the input"),
+    (
+        &[Key::Alt('b'), Key::Alt('f')],
+        "Move a word back or forward",
+    ),
+    (
+        &[Key::Backspace, Key::Ctrl('h')],
+        "Delete the character before the cursor",
+    ),
+    (
+        &[Key::Delete, Key::Ctrl('d')],
+        "Delete the character under the cursor",
+    ),
+    (&[Key::Ctrl('w')], "Delete the word before the cursor"),
+    (&[Key::Ctrl('u')], "Delete everything before the cursor"),
+    (&[Key::Ctrl('k')], "Delete everything after the cursor"),
+    (&[Key::Ctrl('y')], "Paste the last deleted text"),
+    (&[Key::Ctrl('z')], "Undo"),
+];
+
+/// A single line text field with a cursor, word motions and undo history
+#[derive(Clone, Default)]
+pub struct LineEditor {
+    text: String,
+    /// Cursor position, counted in chars rather than bytes
+    cursor: usize,
+    history: Vec<(String, usize)>,
+    typing: bool,
+}
+
+impl LineEditor {
+    pub fn new() -> LineEditor {
+        LineEditor::default()
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Display width of the text in front of the cursor, for placing the terminal cursor
+    pub fn cursor_width(&self) -> usize {
+        self.text[..self.byte_index(self.cursor)].width()
+    }
+
+    /// Applies a line editing key, killed text goes to and is yanked from `kill_buffer`
+    pub fn edit(&mut self, key: Key, kill_buffer: &mut String) {
+        match key {
+            Key::Char(c) => self.insert(c),
+            Key::Backspace | Key::Ctrl('h') => self.backspace(),
+            Key::Delete | Key::Ctrl('d') => self.delete(),
+            Key::Left | Key::Ctrl('b') => self.left(),
+            Key::Right | Key::Ctrl('f') => self.right(),
+            Key::Home | Key::Ctrl('a') => self.home(),
+            Key::End | Key::Ctrl('e') => self.end(),
+            Key::Alt('b') => self.word_left(),
+            Key::Alt('f') => self.word_right(),
+            Key::Ctrl('w') => *kill_buffer = self.delete_word_back(),
+            Key::Ctrl('u') => *kill_buffer = self.kill_to_start(),
+            Key::Ctrl('k') => *kill_buffer = self.kill_to_end(),
+            Key::Ctrl('y') => self.insert_str(kill_buffer),
+            Key::Ctrl('z') => self.undo(),
+            _ => {}
+        }
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.snapshot();
+        self.text = String::from(text);
+        self.cursor = self.len();
+    }
+
+    pub fn insert(&mut self, c: char) {
+        // Typing a word is undone in one go, a new undo step starts at each word boundary
+        if !self.typing || c.is_whitespace() {
+            self.snapshot();
+        }
+        let index = self.byte_index(self.cursor);
+        self.text.insert(index, c);
+        self.cursor += 1;
+        self.typing = true;
+    }
+
+    pub fn insert_str(&mut self, text: &str) {
+        self.snapshot();
+        let index = self.byte_index(self.cursor);
+        self.text.insert_str(index, text);
+        self.cursor += text.chars().count();
+    }
+
+    pub fn backspace(&mut self) {
+        if self.cursor > 0 {
+            self.remove(self.cursor - 1, self.cursor);
+        }
+    }
+
+    pub fn delete(&mut self) {
+        if self.cursor < self.len() {
+            self.remove(self.cursor, self.cursor + 1);
+        }
+    }
+
+    /// Removes the word in front of the cursor and returns it
+    pub fn delete_word_back(&mut self) -> String {
+        let start = self.previous_word(self.cursor);
+        self.remove(start, self.cursor)
+    }
+
+    /// Removes everything in front of the cursor and returns it
+    pub fn kill_to_start(&mut self) -> String {
+        self.remove(0, self.cursor)
+    }
+
+    /// Removes everything after the cursor and returns it
+    pub fn kill_to_end(&mut self) -> String {
+        self.remove(self.cursor, self.len())
+    }
+
+    pub fn undo(&mut self) {
+        if let Some((text, cursor)) = self.history.pop() {
+            self.text = text;
+            self.cursor = cursor;
+        }
+        self.typing = false;
+    }
+
+    pub fn left(&mut self) {
+        self.move_to(self.cursor.saturating_sub(1));
+    }
+
+    pub fn right(&mut self) {
+        self.move_to((self.cursor + 1).min(self.len()));
+    }
+
+    pub fn home(&mut self) {
+        self.move_to(0);
+    }
+
+    pub fn end(&mut self) {
+        self.move_to(self.len());
+    }
+
+    pub fn word_left(&mut self) {
+        self.move_to(self.previous_word(self.cursor));
+    }
+
+    pub fn word_right(&mut self) {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut i = self.cursor;
+        while i < chars.len() && !is_word_char(chars[i]) {
+            i += 1;
+        }
+        while i < chars.len() && is_word_char(chars[i]) {
+            i += 1;
+        }
+        self.move_to(i);
+    }
+
+    fn move_to(&mut self, cursor: usize) {
+        self.cursor = cursor;
+        self.typing = false;
+    }
+
+    fn previous_word(&self, from: usize) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut i = from;
+        while i > 0 && !is_word_char(chars[i - 1]) {
+            i -= 1;
+        }
+        while i > 0 && is_word_char(chars[i - 1]) {
+            i -= 1;
+        }
+        i
+    }
+
+    fn remove(&mut self, start: usize, end: usize) -> String {
+        if start == end {
+            return String::new();
+        }
+        self.snapshot();
+        let (start_byte, end_byte) = (self.byte_index(start), self.byte_index(end));
+        let removed = self.text.drain(start_byte..end_byte).collect();
+        self.cursor = start;
+        removed
+    }
+
+    fn snapshot(&mut self) {
+        self.history.push((self.text.to_owned(), self.cursor));
+        self.typing = false;
+    }
+
+    fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    fn byte_index(&self, cursor: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(cursor)
+            .map(|(i, _)| i)
+            .unwrap_or_else(|| self.text.len())
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '.' || c == '_'
+}
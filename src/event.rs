@@ -1,6 +1,11 @@
 use {
+    signal_hook::{
+        consts::{SIGCONT, SIGWINCH},
+        iterator::Signals,
+    },
     std::{
         io,
+        panic::{self, AssertUnwindSafe},
         sync::{
             atomic::{AtomicBool, Ordering},
             mpsc, Arc,
@@ -8,89 +13,133 @@ use {
         thread,
         time::Duration,
     },
-    termion::{event::Key, input::TermRead},
+    termion::{
+        event::{self as term_event, Key, MouseEvent},
+        input::TermRead,
+    },
 };
 
 pub enum Event<I> {
     Input(I),
+    Mouse(MouseEvent),
+    /// The terminal was resized, or FeStats was resumed after a suspend
+    Resize,
     Tick,
+    /// One of the event threads panicked, the panic hook has its report
+    Crashed,
 }
 
-/// A small event handler that wrap termion input and tick events. Each event
-/// type is handled in its own thread and returned to a common `Receiver`
+/// A small event handler that wrap termion input, mouse, signal and tick events. Each event
+/// type is handled in its own thread and returned to a common `Receiver`.
+/// Ticks are off until something needs animating, so an idle screen costs no CPU.
 pub struct Events {
     rx: mpsc::Receiver<Event<Key>>,
     input_handle: thread::JoinHandle<()>,
-    ignore_exit_key: Arc<AtomicBool>,
+    ticking: Arc<AtomicBool>,
     tick_handle: thread::JoinHandle<()>,
 }
 
+/// Quitting goes through the key map like every other action, so there is no exit key here
 #[derive(Debug, Clone, Copy)]
 pub struct Config {
-    pub exit_key: Key,
     pub tick_rate: Duration,
 }
 
 impl Default for Config {
     fn default() -> Config {
         Config {
-            exit_key: Key::Char('q'),
             tick_rate: Duration::from_millis(250),
         }
     }
 }
 
 impl Events {
-    pub fn new() -> Events {
+    pub fn new() -> io::Result<Events> {
         Events::with_config(Config::default())
     }
 
-    pub fn with_config(config: Config) -> Events {
+    pub fn with_config(config: Config) -> io::Result<Events> {
         let (tx, rx) = mpsc::channel();
-        let ignore_exit_key = Arc::new(AtomicBool::new(false));
         let input_handle = {
             let tx = tx.clone();
-            let ignore_exit_key = ignore_exit_key.clone();
-            thread::spawn(move || {
+            spawn(tx.clone(), move || {
                 let stdin = io::stdin();
-                for evt in stdin.keys() {
-                    if let Ok(key) = evt {
-                        if let Err(err) = tx.send(Event::Input(key)) {
-                            eprintln!("{}", err);
-                            return;
+                for evt in stdin.events() {
+                    match evt {
+                        Ok(term_event::Event::Key(key)) => {
+                            if let Err(err) = tx.send(Event::Input(key)) {
+                                eprintln!("{}", err);
+                                return;
+                            }
                         }
-                        if !ignore_exit_key.load(Ordering::Relaxed) && key == config.exit_key {
-                            return;
+                        Ok(term_event::Event::Mouse(mouse)) => {
+                            if let Err(err) = tx.send(Event::Mouse(mouse)) {
+                                eprintln!("{}", err);
+                                return;
+                            }
                         }
+                        _ => {}
                     }
                 }
             })
         };
+        {
+            let tx = tx.clone();
+            let mut signals = Signals::new([SIGWINCH, SIGCONT])?;
+            spawn(tx.clone(), move || {
+                for _ in signals.forever() {
+                    if tx.send(Event::Resize).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        let ticking = Arc::new(AtomicBool::new(false));
         let tick_handle = {
-            thread::spawn(move || loop {
+            let ticking = ticking.clone();
+            spawn(tx.clone(), move || loop {
+                if !ticking.load(Ordering::Relaxed) {
+                    // Woken up again by `enable_ticks`
+                    thread::park();
+                    continue;
+                }
                 if tx.send(Event::Tick).is_err() {
                     break;
                 }
                 thread::sleep(config.tick_rate);
             })
         };
-        Events {
+        Ok(Events {
             rx,
-            ignore_exit_key,
             input_handle,
+            ticking,
             tick_handle,
-        }
+        })
     }
 
     pub fn next(&self) -> Result<Event<Key>, mpsc::RecvError> {
         self.rx.recv()
     }
 
-    pub fn disable_exit_key(&mut self) {
-        self.ignore_exit_key.store(true, Ordering::Relaxed);
+    pub fn enable_ticks(&mut self) {
+        self.ticking.store(true, Ordering::Relaxed);
+        self.tick_handle.thread().unpark();
     }
 
-    pub fn enable_exit_key(&mut self) {
-        self.ignore_exit_key.store(false, Ordering::Relaxed);
+    pub fn disable_ticks(&mut self) {
+        self.ticking.store(false, Ordering::Relaxed);
     }
 }
+
+/// Spawns an event thread that reports a panic over the channel. The main thread is the one
+/// that can restore the terminal, a thread dying quietly would just stop its events.
+fn spawn<F>(tx: mpsc::Sender<Event<Key>>, body: F) -> thread::JoinHandle<()>
+where
+    F: FnOnce() + Send + 'static,
+{
+    thread::spawn(move || {
+        if panic::catch_unwind(AssertUnwindSafe(body)).is_err() {
+            tx.send(Event::Crashed).ok();
+        }
+    })
+}
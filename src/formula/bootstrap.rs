@@ -0,0 +1,324 @@
+use super::random::parse_seed;
+use super::statistics::{correlation, mean, median, parse_list, percentile, std_dev};
+
+use {
+    rand::{rngs::StdRng, Rng, SeedableRng},
+    statrs::distribution::{InverseCDF, Normal, Univariate},
+};
+
+/// Name of the formula the resampling runs under
+pub const BOOTSTRAP: &str = "Bootstrap Interval";
+
+const MAX_RESAMPLES: usize = 100_000;
+
+/// Values drawn per tick at most, so the progress bar keeps moving however big the data is
+const DRAWS_PER_TICK: usize = 200_000;
+
+/// Statistics the Bootstrap Interval formula accepts, the last two take a second list
+pub const STATISTICS: [&str; 5] = ["Mean", "Median", "SD", "Correlation", "Difference"];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Statistic {
+    Mean,
+    Median,
+    StdDev,
+    /// Pearson's r of paired lists, pairs are resampled together
+    Correlation,
+    /// Mean of the first list minus the mean of the second, each resampled on its own
+    Difference,
+}
+
+impl Statistic {
+    pub fn parse(text: &str) -> Option<Statistic> {
+        match text.trim().to_lowercase().as_str() {
+            "mean" | "x̄" => Some(Statistic::Mean),
+            "median" => Some(Statistic::Median),
+            "sd" | "sx" | "standard deviation" | "std dev" => Some(Statistic::StdDev),
+            "correlation" | "r" => Some(Statistic::Correlation),
+            "difference" | "difference of means" | "mean difference" => Some(Statistic::Difference),
+            _ => None,
+        }
+    }
+
+    fn compute(self, x: &[f64], y: &[f64]) -> f64 {
+        match self {
+            Statistic::Mean => mean(x),
+            Statistic::Median => median(x),
+            Statistic::StdDev => std_dev(x),
+            Statistic::Correlation => correlation(x, y),
+            Statistic::Difference => mean(x) - mean(y),
+        }
+    }
+}
+
+/// Resamples a data list B times with replacement, a batch on every tick, then gives
+/// percentile, basic and BCa intervals for the statistic
+pub struct Bootstrap {
+    pub statistic: Statistic,
+    pub resamples: usize,
+    pub seed: u64,
+    x: Vec<f64>,
+    y: Vec<f64>,
+    c_level: f64,
+    estimates: Vec<f64>,
+    rng: StdRng,
+}
+
+impl Bootstrap {
+    /// Reads the Bootstrap Interval inputs, or explains what is wrong with them
+    pub fn from_inputs(inputs: &[String]) -> Result<Bootstrap, String> {
+        let statistic = Statistic::parse(&inputs[0])
+            .ok_or_else(|| format!("Ensure the statistic is one of {}", STATISTICS.join(", ")))?;
+
+        let x = parse_list(&inputs[1]).map_err(|err| err.to_string())?;
+        let y = match inputs[2].trim() {
+            "" => Vec::new(),
+            list => parse_list(list).map_err(|err| err.to_string())?,
+        };
+
+        match statistic {
+            Statistic::Correlation if x.len() != y.len() || x.len() < 3 => {
+                return Err(String::from(
+                    "Ensure both lists have the same length, with at least 3 pairs",
+                ))
+            }
+            Statistic::Difference if x.len() < 2 || y.len() < 2 => {
+                return Err(String::from(
+                    "Ensure both lists have at least 2 numbers, the second list is needed for a \
+                    difference",
+                ))
+            }
+            _ if x.len() < 2 => {
+                return Err(String::from("Ensure the data list has at least 2 numbers"))
+            }
+            _ => {}
+        }
+
+        let resamples = match inputs[3].trim().parse::<usize>() {
+            Ok(b) if (100..=MAX_RESAMPLES).contains(&b) => b,
+            _ => {
+                return Err(String::from(
+                    "Ensure B is a whole number from 100 to 100000",
+                ))
+            }
+        };
+
+        let c_level = match inputs[4].trim().parse::<f64>() {
+            Ok(c) if c > 0.0 && c < 100.0 => c / 100.0,
+            _ => return Err(String::from("Ensure the C Level is between 0 and 100")),
+        };
+
+        let seed = match parse_seed(&inputs[5]) {
+            Some(seed) => seed.unwrap_or_else(|| rand::thread_rng().gen_range(0..1_000_000)),
+            None => return Err(String::from("Ensure the seed is a whole number")),
+        };
+
+        Ok(Bootstrap {
+            statistic,
+            resamples,
+            seed,
+            x,
+            y,
+            c_level,
+            estimates: Vec::with_capacity(resamples),
+            rng: StdRng::seed_from_u64(seed),
+        })
+    }
+
+    /// Resamples done so far, as a fraction of B
+    pub fn progress(&self) -> f64 {
+        self.estimates.len() as f64 / self.resamples as f64
+    }
+
+    pub fn completed(&self) -> usize {
+        self.estimates.len()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.estimates.len() >= self.resamples
+    }
+
+    /// Draws the next batch of resamples
+    pub fn step(&mut self) {
+        let size = self.x.len() + self.y.len();
+        let batch = (DRAWS_PER_TICK / size)
+            .max(1)
+            .min(self.resamples - self.estimates.len());
+        let mut x = vec![0.0; self.x.len()];
+        let mut y = vec![0.0; self.y.len()];
+
+        for _ in 0..batch {
+            match self.statistic {
+                // Pairs stay together, so the same indices pick from both lists
+                Statistic::Correlation => {
+                    for (x_value, y_value) in x.iter_mut().zip(y.iter_mut()) {
+                        let j = self.rng.gen_range(0..self.x.len());
+                        *x_value = self.x[j];
+                        *y_value = self.y[j];
+                    }
+                }
+                _ => {
+                    for value in x.iter_mut() {
+                        *value = self.x[self.rng.gen_range(0..self.x.len())];
+                    }
+                    for value in y.iter_mut() {
+                        *value = self.y[self.rng.gen_range(0..self.y.len())];
+                    }
+                }
+            }
+            self.estimates.push(self.statistic.compute(&x, &y));
+        }
+    }
+
+    /// The estimate with its standard error and three intervals, once every resample is drawn
+    pub fn result(&self) -> String {
+        let estimate = self.statistic.compute(&self.x, &self.y);
+        let mut sorted: Vec<f64> = self
+            .estimates
+            .iter()
+            .copied()
+            .filter(|x| x.is_finite())
+            .collect();
+        if sorted.len() < 2 {
+            return String::from(
+                "Almost every resample repeats one value, so the statistic can't be bootstrapped",
+            );
+        }
+        sorted.sort_by(f64::total_cmp);
+
+        let alpha = (1.0 - self.c_level) / 2.0;
+        let (lower, upper) = (percentile(&sorted, alpha), percentile(&sorted, 1.0 - alpha));
+
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let (bca_lower, bca_upper) = self.bca_levels(estimate, &sorted, alpha, &normal);
+
+        format!(
+            "Estimate: {}\nStd Error: {}\nPercentile Lower: {}\nPercentile Upper: {}\n\
+            Basic Lower: {}\nBasic Upper: {}\nBCa Lower: {}\nBCa Upper: {}\n\
+            Resamples: {}\nSeed: {}",
+            estimate,
+            std_dev(&sorted),
+            lower,
+            upper,
+            2.0 * estimate - upper,
+            2.0 * estimate - lower,
+            percentile(&sorted, bca_lower),
+            percentile(&sorted, bca_upper),
+            sorted.len(),
+            self.seed
+        )
+    }
+
+    /// Percentiles of the resamples the BCa interval uses, shifted for the bias of the
+    /// estimate and skewed for how fast its standard error changes, from a jackknife
+    fn bca_levels(&self, estimate: f64, sorted: &[f64], alpha: f64, normal: &Normal) -> (f64, f64) {
+        let below = sorted.iter().filter(|&&x| x < estimate).count() as f64;
+        let ties = sorted.iter().filter(|&&x| x == estimate).count() as f64;
+        // Kept off 0 and 1, where the bias correction would be infinite
+        let half = 0.5 / sorted.len() as f64;
+        let bias = normal
+            .inverse_cdf(((below + ties / 2.0) / sorted.len() as f64).clamp(half, 1.0 - half));
+
+        let jackknife = self.jackknife();
+        let average = mean(&jackknife);
+        let (squares, cubes) = jackknife.iter().fold((0.0, 0.0), |(squares, cubes), x| {
+            let d = average - x;
+            (squares + d.powi(2), cubes + d.powi(3))
+        });
+        let acceleration = if squares > 0.0 {
+            cubes / (6.0 * squares.powf(1.5))
+        } else {
+            0.0
+        };
+
+        let level = |p: f64| {
+            let z = bias + normal.inverse_cdf(p);
+            normal.cdf(bias + z / (1.0 - acceleration * z))
+        };
+        (level(alpha), level(1.0 - alpha))
+    }
+
+    /// The statistic with each observation left out in turn, a pair for a correlation and
+    /// either list's values for a difference
+    fn jackknife(&self) -> Vec<f64> {
+        let without = |list: &[f64], i: usize| {
+            let mut rest = list.to_vec();
+            rest.remove(i);
+            rest
+        };
+
+        let values: Vec<f64> = match self.statistic {
+            Statistic::Correlation => (0..self.x.len())
+                .map(|i| correlation(&without(&self.x, i), &without(&self.y, i)))
+                .collect(),
+            Statistic::Difference => (0..self.x.len())
+                .map(|i| mean(&without(&self.x, i)) - mean(&self.y))
+                .chain((0..self.y.len()).map(|i| mean(&self.x) - mean(&without(&self.y, i))))
+                .collect(),
+            statistic => (0..self.x.len())
+                .map(|i| statistic.compute(&without(&self.x, i), &[]))
+                .collect(),
+        };
+        values.into_iter().filter(|x| x.is_finite()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variables::{result_fields, Value};
+
+    fn bootstrap(inputs: [&str; 6]) -> Vec<(String, Value)> {
+        let mut bootstrap = Bootstrap::from_inputs(&inputs.map(String::from)).unwrap();
+        while !bootstrap.is_finished() {
+            bootstrap.step();
+        }
+        result_fields(&bootstrap.result())
+    }
+
+    #[test]
+    fn bootstrap_intervals_surround_the_estimate() {
+        let data = "2, 4, 4, 5, 7, 9, 3, 6, 8, 5, 4, 6, 7, 3, 5";
+        let fields = bootstrap(["Mean", data, "", "4000", "95", "11"]);
+        let field = |label: &str| match fields.iter().find(|(name, _)| name == label) {
+            Some((_, Value::Number(x))) => *x,
+            _ => panic!("no {} in the result", label),
+        };
+
+        let estimate = field("Estimate");
+        assert!((estimate - 5.2).abs() < 1e-12);
+        for method in &["Percentile", "Basic", "BCa"] {
+            let lower = field(&format!("{} Lower", method));
+            let upper = field(&format!("{} Upper", method));
+            assert!(lower < estimate && estimate < upper);
+            // Close to the t interval of (4.155, 6.245) for data this symmetric
+            assert!((lower - 4.155).abs() < 0.25 && (upper - 6.245).abs() < 0.25);
+        }
+
+        // The same seed draws the same resamples
+        assert_eq!(fields, bootstrap(["Mean", data, "", "4000", "95", "11"]));
+    }
+
+    #[test]
+    fn bootstrap_statistics_check_their_lists() {
+        let inputs = |statistic: &str, y: &str| {
+            [statistic, "1, 2, 3, 4", y, "1000", "95", "1"].map(String::from)
+        };
+
+        assert!(Bootstrap::from_inputs(&inputs("Correlation", "1, 2")).is_err());
+        assert!(Bootstrap::from_inputs(&inputs("Difference", "")).is_err());
+        assert!(Bootstrap::from_inputs(&inputs("Mode", "")).is_err());
+        assert!(Bootstrap::from_inputs(&inputs("Median", "1, nan")).is_err());
+        assert!(Bootstrap::from_inputs(&inputs("Correlation", "2, 1, 4, 3")).is_ok());
+
+        let fields = bootstrap([
+            "Difference",
+            "5, 6, 7, 8, 9",
+            "1, 2, 3, 4, 5",
+            "2000",
+            "90",
+            "3",
+        ]);
+        assert_eq!(fields[0], (String::from("Estimate"), Value::Number(4.0)));
+    }
+}
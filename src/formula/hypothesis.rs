@@ -0,0 +1,66 @@
+use {
+    statrs::function::{erf::erfc, gamma::gamma_ur},
+    std::f64::consts::SQRT_2,
+};
+
+/// Message for an Alternate Hyp input that isn't one of the directions
+pub const ALTERNATIVE_MESSAGE: &str = "Ensure the alternate hypothesis is ≠, < or >";
+
+/// Direction of the alternative hypothesis a test is run against
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Alternative {
+    NotEqual,
+    Less,
+    Greater,
+}
+
+impl Alternative {
+    pub fn parse(text: &str) -> Option<Alternative> {
+        match text.trim().to_lowercase().as_str() {
+            "≠" | "!=" | "<>" | "ne" | "two sided" | "two-sided" => Some(Alternative::NotEqual),
+            "<" | "less" => Some(Alternative::Less),
+            ">" | "greater" => Some(Alternative::Greater),
+            _ => None,
+        }
+    }
+
+    /// Whether a statistic is at least as far from the null as the observed one, in the
+    /// direction of the alternative. Ties are counted with a little room for rounding.
+    pub fn is_extreme(self, statistic: f64, observed: f64) -> bool {
+        let tolerance = 1e-9 * observed.abs().max(1.0);
+        match self {
+            Alternative::NotEqual => statistic.abs() >= observed.abs() - tolerance,
+            Alternative::Less => statistic <= observed + tolerance,
+            Alternative::Greater => statistic >= observed - tolerance,
+        }
+    }
+
+    /// The p value from the probabilities of a statistic at most and at least the observed
+    /// one, doubling the smaller for a two sided test
+    pub fn p_value(self, lower: f64, upper: f64) -> f64 {
+        match self {
+            Alternative::NotEqual => (2.0 * lower.min(upper)).min(1.0),
+            Alternative::Less => lower.min(1.0),
+            Alternative::Greater => upper.min(1.0),
+        }
+    }
+}
+
+/// P(Z ≤ z) for a standard normal Z
+pub fn normal_cdf(z: f64) -> f64 {
+    0.5 * erfc(-z / SQRT_2)
+}
+
+/// P(Z ≥ z) for a standard normal Z, precise far into the upper tail
+pub fn normal_sf(z: f64) -> f64 {
+    0.5 * erfc(z / SQRT_2)
+}
+
+/// P(X ≥ x) for X with a χ2 distribution
+pub fn chi_square_sf(x: f64, df: f64) -> f64 {
+    if x <= 0.0 {
+        1.0
+    } else {
+        gamma_ur(df / 2.0, x / 2.0)
+    }
+}
@@ -1,25 +1,216 @@
-use crate::app::App;
+use crate::{
+    app::App,
+    simulation::{Simulation, MAX_SAMPLES, SIMULATION},
+};
+use bootstrap::{Bootstrap, BOOTSTRAP};
+use nonparametric::{
+    get_friedman_test, get_kruskal_wallis_test, get_mann_whitney_test, get_sign_test,
+    get_signed_rank_test,
+};
+use normality::{get_anderson_darling, get_jarque_bera, get_kolmogorov_smirnov, get_shapiro_wilk};
+use paired::{get_paired_t_interval, get_paired_t_test};
+use permutation::get_permutation_test;
 use probability::{
-    get_binom_cdf, get_binom_pdf, get_chi_square_cdf, get_chi_square_pdf, get_combination,
-    get_f_cdf, get_f_pdf, get_factorial, get_geo_cdf, get_geo_pdf, get_inv_normal, get_normal_cdf,
-    get_normal_pdf, get_permutation, get_poisson_cdf, get_poisson_pdf, get_t_cdf, get_t_pdf,
+    get_bernoulli_cdf, get_bernoulli_pdf, get_beta_cdf, get_beta_pdf, get_binom_cdf, get_binom_pdf,
+    get_cauchy_cdf, get_cauchy_pdf, get_chi_square_cdf, get_chi_square_pdf, get_combination,
+    get_discrete_uniform_cdf, get_discrete_uniform_pdf, get_exp_cdf, get_exp_pdf, get_f_cdf,
+    get_f_pdf, get_factorial, get_gamma_cdf, get_gamma_pdf, get_geo_cdf, get_geo_pdf,
+    get_hypergeo_cdf, get_hypergeo_pdf, get_inv_bernoulli, get_inv_beta, get_inv_cauchy,
+    get_inv_chi_square, get_inv_discrete_uniform, get_inv_exp, get_inv_gamma, get_inv_hypergeo,
+    get_inv_logistic, get_inv_lognormal, get_inv_neg_binom, get_inv_normal, get_inv_scaled_t,
+    get_inv_t, get_inv_uniform, get_inv_weibull, get_logistic_cdf, get_logistic_pdf,
+    get_lognormal_cdf, get_lognormal_pdf, get_multinomial_pdf, get_neg_binom_cdf,
+    get_neg_binom_pdf, get_normal_cdf, get_normal_pdf, get_permutation, get_poisson_cdf,
+    get_poisson_pdf, get_scaled_t_cdf, get_scaled_t_pdf, get_t_cdf, get_t_pdf, get_uniform_cdf,
+    get_uniform_pdf, get_weibull_cdf, get_weibull_pdf, Range,
+};
+use random::{get_rand_bin, get_rand_int, get_rand_norm, get_random_sample, parse_seed};
+use statistics::{parse_list, parse_lists, FINITE_MESSAGE};
+use variance::{
+    get_2_samp_f_interval, get_bartlett_test, get_chi_square_variance_interval,
+    get_chi_square_variance_test, get_levene_test, Center,
 };
 
 use anyhow::Result;
 
+pub mod bootstrap;
 pub mod distributions;
+pub mod hypothesis;
 pub mod intervals;
+pub mod nonparametric;
+pub mod normality;
+pub mod paired;
+pub mod permutation;
 pub mod probability;
+pub mod random;
+pub mod reference;
 pub mod regressions;
+pub mod statistics;
+pub mod variance;
 
 const C_LEVELS: [&str; 3] = ["90", "95", "99"];
 
+/// Every tab and the formulas listed on it, in order
+pub const TABS: [(&str, &[&str]); 5] = [
+    (
+        "Probability",
+        &[
+            "Factorial (!)",
+            "Permutations",
+            "Combinations",
+            "Normal Pdf",
+            "Normal Cdf",
+            "Inverse Normal",
+            "t Pdf",
+            "t Cdf",
+            "Inverse t",
+            "χ2 Pdf",
+            "χ2 Cdf",
+            "Inverse χ2",
+            "Binomial Pdf",
+            "Binomial Cdf",
+            "F Pdf",
+            "F Cdf",
+            "Geometric Pdf",
+            "Geometric Cdf",
+            "Poisson Pdf",
+            "Poisson Cdf",
+            "Hypergeometric Pdf",
+            "Hypergeometric Cdf",
+            "Inverse Hypergeometric",
+            "Negative Binomial Pdf",
+            "Negative Binomial Cdf",
+            "Inverse Negative Binomial",
+            "Discrete Uniform Pdf",
+            "Discrete Uniform Cdf",
+            "Inverse Discrete Uniform",
+            "Bernoulli Pdf",
+            "Bernoulli Cdf",
+            "Inverse Bernoulli",
+            "Multinomial Pdf",
+            "Exponential Pdf",
+            "Exponential Cdf",
+            "Inverse Exponential",
+            "Uniform Pdf",
+            "Uniform Cdf",
+            "Inverse Uniform",
+            "Gamma Pdf",
+            "Gamma Cdf",
+            "Inverse Gamma",
+            "Beta Pdf",
+            "Beta Cdf",
+            "Inverse Beta",
+            "Lognormal Pdf",
+            "Lognormal Cdf",
+            "Inverse Lognormal",
+            "Weibull Pdf",
+            "Weibull Cdf",
+            "Inverse Weibull",
+            "Cauchy Pdf",
+            "Cauchy Cdf",
+            "Inverse Cauchy",
+            "Logistic Pdf",
+            "Logistic Cdf",
+            "Inverse Logistic",
+            "Scaled t Pdf",
+            "Scaled t Cdf",
+            "Inverse Scaled t",
+        ],
+    ),
+    (
+        "Random",
+        &[
+            "randInt",
+            "randNorm",
+            "randBin",
+            "Random Sample",
+            SIMULATION,
+        ],
+    ),
+    (
+        "Intervals",
+        &[
+            "z Interval",
+            "t Interval",
+            "2-Sample z Interval",
+            "2-Sample t Interval",
+            "1-Prop z Interval",
+            "2-Prop z Interval",
+            "Paired t Interval",
+            "χ2 Variance Interval",
+            "2-Sample F Interval",
+            BOOTSTRAP,
+        ],
+    ),
+    (
+        "Tests",
+        &[
+            "z Test",
+            "t Test",
+            "2-Sample z Test",
+            "2-Sample t Test",
+            "1-Prop z Test",
+            "2-Prop z Test",
+            "χ2 GOF",
+            "χ2 2-way Test",
+            "2-Sample F Test",
+            "ANOVA",
+            "Paired t Test",
+            "Permutation Test",
+            "Sign Test",
+            "Wilcoxon Signed-Rank Test",
+            "Mann-Whitney U Test",
+            "Kruskal-Wallis Test",
+            "Friedman Test",
+            "Shapiro-Wilk Test",
+            "Anderson-Darling Test",
+            "Kolmogorov-Smirnov Test",
+            "Jarque-Bera Test",
+            "χ2 Variance Test",
+            "Levene Test",
+            "Brown-Forsythe Test",
+            "Bartlett Test",
+        ],
+    ),
+    (
+        "Regressions",
+        &[
+            "Linear Regression (mx+b)",
+            "Linear Regression (a+bx)",
+            "Median-Median Line",
+            "Quadratic Regression",
+            "Cubic Regression",
+            "Quartic Regression",
+        ],
+    ),
+];
+
+const BOUNDS_MESSAGE: &str = "Bounds must be numbers, inf, -inf or blank, or a shortcut like \
+    left of x, right of x, between a and b or outside a and b. Please try again.";
+
+const SAMPLE_MESSAGE: &str =
+    "The sample size and seed must be whole numbers without a decimal. Please try again.";
+
+const RANGE_MESSAGE: &str =
+    "Range must be ≤, <, ≥, >, between or outside, and the bounds numbers. Please try again.";
+
 pub fn retrieve_formula(formula_name: &str) -> Vec<String> {
     match_formula_inputs(formula_name)
 }
 
+pub fn retrieve_aliases(formula_name: &str) -> Vec<&'static str> {
+    match_formula_aliases(formula_name)
+}
+
 pub fn attempt_formula(app: &mut App, formula_name: &str, inputs: &Vec<String>) -> Result<String> {
-    if inputs.is_empty() || inputs.len() - 1 < retrieve_formula(formula_name).len() {
+    let labels = retrieve_formula(formula_name);
+
+    if inputs.len() < labels.len()
+        || inputs
+            .iter()
+            .zip(&labels)
+            .any(|(input, label)| input.trim().is_empty() && !is_optional(label))
+    {
         if formula_name.contains("Interval") {
             Ok(format!(
                 "All inputs are not filled yet.\nPlease select a C-Level of{}.",
@@ -35,7 +226,6 @@ pub fn attempt_formula(app: &mut App, formula_name: &str, inputs: &Vec<String>)
         if formula_name.contains("Regression") || formula_name.contains("Median-Media") {
             let inputs: &Vec<Vec<String>> = &inputs
                 .iter()
-                .skip(1)
                 .map(|x| {
                     String::from(x)
                         .trim()
@@ -61,17 +251,32 @@ pub fn attempt_formula(app: &mut App, formula_name: &str, inputs: &Vec<String>)
             }
             match_regressions_formula_equations(formula_name, &inputs[0], &inputs[1])
         } else {
-            let inputs: &Vec<String> = &inputs.iter().skip(1).map(|x| String::from(x)).collect();
-            for input in inputs {
-                let input = input.trim().parse::<f64>();
-
-                match input {
-                    Ok(_) => {}
-                    Err(_) => {
-                        return Ok(String::from(
-                            "Not all inputs are numbers. Please enter them again.",
-                        ))
-                    }
+            let inputs: &Vec<String> = &inputs.iter().map(|x| String::from(x.trim())).collect();
+            for (input, label) in inputs.iter().zip(&labels) {
+                // List inputs hold comma separated numbers, several lists or a matrix's rows are
+                // separated by semicolons, text and optional inputs are checked
+                // when they are parsed, every other input is a number
+                let all_numbers = if is_text(label) || is_optional(label) {
+                    true
+                } else if label.ends_with("List") {
+                    input.split(',').all(|x| x.trim().parse::<f64>().is_ok())
+                } else if label.ends_with("Lists") || label.ends_with("Matrix") {
+                    parse_lists(input).is_ok()
+                } else {
+                    input.trim().parse::<f64>().is_ok()
+                };
+
+                if !all_numbers {
+                    return Ok(String::from(
+                        "Not all inputs are numbers. Please enter them again.",
+                    ));
+                }
+                // nan and inf parse as numbers, but the list statistics can't sort or rank them
+                let is_list = label.ends_with("List")
+                    || label.ends_with("Lists")
+                    || label.ends_with("Matrix");
+                if is_list && !is_optional(label) && parse_lists(input).is_err() {
+                    return Ok(String::from(FINITE_MESSAGE));
                 }
             }
             match_formula_equations(app, formula_name, inputs)
@@ -79,6 +284,23 @@ pub fn attempt_formula(app: &mut App, formula_name: &str, inputs: &Vec<String>)
     }
 }
 
+/// Whether an input holds a word, like the Range selector or a distribution's name
+fn is_text(label: &str) -> bool {
+    matches!(
+        label,
+        "Range" | "Distribution" | "Statistic" | "Compare" | "Alternate Hyp"
+    )
+}
+
+/// Whether an input may be left blank, bounds are then infinite, a seed is picked at random, a
+/// second list is only needed by some statistics and a normal without parameters is fitted
+fn is_optional(label: &str) -> bool {
+    matches!(
+        label,
+        "Lower Bound" | "Upper Bound" | "Seed" | "Second List" | "Normal µ" | "Normal σ"
+    )
+}
+
 fn match_formula_equations(
     app: &mut App,
     formula_name: &str,
@@ -133,29 +355,29 @@ fn match_formula_equations(
             input[1].parse::<f64>()?,
             input[2].parse::<f64>()?,
         ),
-        "Normal Cdf" => get_normal_cdf(
-            input[0].parse::<f64>()?,
-            input[1].parse::<f64>()?,
-            input[2].parse::<f64>()?,
-            input[3].parse::<f64>()?,
-        ),
+        "Normal Cdf" => match Range::parse_bounds(&input[0], &input[1]) {
+            Some(range) => {
+                get_normal_cdf(range, input[2].parse::<f64>()?, input[3].parse::<f64>()?)
+            }
+            None => Ok(String::from(BOUNDS_MESSAGE)),
+        },
         "Inverse Normal" => get_inv_normal(
             input[0].parse::<f64>()?,
             input[1].parse::<f64>()?,
             input[2].parse::<f64>()?,
         ),
         "t Pdf" => get_t_pdf(input[0].parse::<f64>()?, input[1].parse::<f64>()?),
-        "t Cdf" => get_t_cdf(
-            input[0].parse::<f64>()?,
-            input[1].parse::<f64>()?,
-            input[2].parse::<f64>()?,
-        ),
+        "t Cdf" => match Range::parse_bounds(&input[0], &input[1]) {
+            Some(range) => get_t_cdf(range, input[2].parse::<f64>()?),
+            None => Ok(String::from(BOUNDS_MESSAGE)),
+        },
+        "Inverse t" => get_inv_t(input[0].parse::<f64>()?, input[1].parse::<f64>()?),
         "χ2 Pdf" => get_chi_square_pdf(input[0].parse::<f64>()?, input[1].parse::<f64>()?),
-        "χ2 Cdf" => get_chi_square_cdf(
-            input[0].parse::<f64>()?,
-            input[1].parse::<f64>()?,
-            input[2].parse::<f64>()?,
-        ),
+        "χ2 Cdf" => match Range::parse_bounds(&input[0], &input[1]) {
+            Some(range) => get_chi_square_cdf(range, input[2].parse::<f64>()?),
+            None => Ok(String::from(BOUNDS_MESSAGE)),
+        },
+        "Inverse χ2" => get_inv_chi_square(input[0].parse::<f64>()?, input[1].parse::<f64>()?),
         "Binomial Pdf" => {
             let n = input[0].parse::<u64>();
             let x = input[2].parse::<u64>();
@@ -175,16 +397,12 @@ fn match_formula_equations(
         "Binomial Cdf" => {
             let n = input[0].parse::<u64>();
 
-            match n {
-                Ok(n) => get_binom_cdf(
-                    n,
-                    input[1].parse::<f64>()?,
-                    input[2].parse::<f64>()?,
-                    input[3].parse::<f64>()?,
-                ),
-                Err(_) => Ok(String::from(
+            match (n, Range::parse(&input[2], &input[3], &input[4])) {
+                (Ok(n), Some(range)) => get_binom_cdf(n, input[1].parse::<f64>()?, range),
+                (Err(_), _) => Ok(String::from(
                     "The number must be an integer without a decimal. Please try again.",
                 )),
+                (_, None) => Ok(String::from(RANGE_MESSAGE)),
             }
         }
         "F Pdf" => get_f_pdf(
@@ -192,12 +410,10 @@ fn match_formula_equations(
             input[2].parse::<f64>()?,
             input[0].parse::<f64>()?,
         ),
-        "F Cdf" => get_f_cdf(
-            input[0].parse::<f64>()?,
-            input[1].parse::<f64>()?,
-            input[2].parse::<f64>()?,
-            input[3].parse::<f64>()?,
-        ),
+        "F Cdf" => match Range::parse_bounds(&input[0], &input[1]) {
+            Some(range) => get_f_cdf(range, input[2].parse::<f64>()?, input[3].parse::<f64>()?),
+            None => Ok(String::from(BOUNDS_MESSAGE)),
+        },
         "Geometric Pdf" => {
             let x = input[1].parse::<u64>();
 
@@ -208,11 +424,10 @@ fn match_formula_equations(
                 )),
             }
         }
-        "Geometric Cdf" => get_geo_cdf(
-            input[0].parse::<f64>()?,
-            input[1].parse::<f64>()?,
-            input[2].parse::<f64>()?,
-        ),
+        "Geometric Cdf" => match Range::parse(&input[1], &input[2], &input[3]) {
+            Some(range) => get_geo_cdf(input[0].parse::<f64>()?, range),
+            None => Ok(String::from(RANGE_MESSAGE)),
+        },
         "Poisson Pdf" => {
             let x = input[1].parse::<u64>();
 
@@ -223,11 +438,311 @@ fn match_formula_equations(
                 )),
             }
         }
-        "Poisson Cdf" => get_poisson_cdf(
+        "Poisson Cdf" => match Range::parse(&input[1], &input[2], &input[3]) {
+            Some(range) => get_poisson_cdf(input[0].parse::<f64>()?, range),
+            None => Ok(String::from(RANGE_MESSAGE)),
+        },
+        "Hypergeometric Pdf" => match (
+            input[0].parse::<u64>(),
+            input[1].parse::<u64>(),
+            input[2].parse::<u64>(),
+            input[3].parse::<u64>(),
+        ) {
+            (Ok(population), Ok(successes), Ok(draws), Ok(x)) => {
+                get_hypergeo_pdf(population, successes, draws, x)
+            }
+            _ => Ok(String::from(
+                "The numbers must be integers without a decimal. Please try again.",
+            )),
+        },
+        "Hypergeometric Cdf" => match (
+            input[0].parse::<u64>(),
+            input[1].parse::<u64>(),
+            input[2].parse::<u64>(),
+            Range::parse(&input[3], &input[4], &input[5]),
+        ) {
+            (Ok(population), Ok(successes), Ok(draws), Some(range)) => {
+                get_hypergeo_cdf(population, successes, draws, range)
+            }
+            (_, _, _, None) => Ok(String::from(RANGE_MESSAGE)),
+            _ => Ok(String::from(
+                "The numbers must be integers without a decimal. Please try again.",
+            )),
+        },
+        "Inverse Hypergeometric" => match (
+            input[1].parse::<u64>(),
+            input[2].parse::<u64>(),
+            input[3].parse::<u64>(),
+        ) {
+            (Ok(population), Ok(successes), Ok(draws)) => {
+                get_inv_hypergeo(input[0].parse::<f64>()?, population, successes, draws)
+            }
+            _ => Ok(String::from(
+                "The numbers must be integers without a decimal. Please try again.",
+            )),
+        },
+        "Negative Binomial Pdf" => match input[2].parse::<u64>() {
+            Ok(x) => get_neg_binom_pdf(input[0].parse::<f64>()?, input[1].parse::<f64>()?, x),
+            Err(_) => Ok(String::from(
+                "The number must be an integer without a decimal. Please try again.",
+            )),
+        },
+        "Negative Binomial Cdf" => match Range::parse(&input[2], &input[3], &input[4]) {
+            Some(range) => {
+                get_neg_binom_cdf(input[0].parse::<f64>()?, input[1].parse::<f64>()?, range)
+            }
+            None => Ok(String::from(RANGE_MESSAGE)),
+        },
+        "Inverse Negative Binomial" => get_inv_neg_binom(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+        ),
+        "Discrete Uniform Pdf" => match (
+            input[0].parse::<i64>(),
+            input[1].parse::<i64>(),
+            input[2].parse::<i64>(),
+        ) {
+            (Ok(min), Ok(max), Ok(x)) => get_discrete_uniform_pdf(min, max, x),
+            _ => Ok(String::from(
+                "The numbers must be integers without a decimal. Please try again.",
+            )),
+        },
+        "Discrete Uniform Cdf" => match (
+            input[0].parse::<i64>(),
+            input[1].parse::<i64>(),
+            Range::parse(&input[2], &input[3], &input[4]),
+        ) {
+            (Ok(min), Ok(max), Some(range)) => get_discrete_uniform_cdf(min, max, range),
+            (_, _, None) => Ok(String::from(RANGE_MESSAGE)),
+            _ => Ok(String::from(
+                "The numbers must be integers without a decimal. Please try again.",
+            )),
+        },
+        "Inverse Discrete Uniform" => match (input[1].parse::<i64>(), input[2].parse::<i64>()) {
+            (Ok(min), Ok(max)) => get_inv_discrete_uniform(input[0].parse::<f64>()?, min, max),
+            _ => Ok(String::from(
+                "The numbers must be integers without a decimal. Please try again.",
+            )),
+        },
+        "Bernoulli Pdf" => match input[1].parse::<u64>() {
+            Ok(x) => get_bernoulli_pdf(input[0].parse::<f64>()?, x),
+            Err(_) => Ok(String::from(
+                "The number must be an integer without a decimal. Please try again.",
+            )),
+        },
+        "Bernoulli Cdf" => match Range::parse(&input[1], &input[2], &input[3]) {
+            Some(range) => get_bernoulli_cdf(input[0].parse::<f64>()?, range),
+            None => Ok(String::from(RANGE_MESSAGE)),
+        },
+        "Inverse Bernoulli" => {
+            get_inv_bernoulli(input[0].parse::<f64>()?, input[1].parse::<f64>()?)
+        }
+        "Multinomial Pdf" => {
+            let n = input[0].parse::<u64>();
+            let probabilities = input[1]
+                .split(',')
+                .map(|x| x.trim().parse::<f64>())
+                .collect::<Result<Vec<f64>, _>>()?;
+            let counts = input[2]
+                .split(',')
+                .map(|x| x.trim().parse::<u64>())
+                .collect::<Result<Vec<u64>, _>>();
+
+            match (n, counts) {
+                (Ok(n), Ok(counts)) => get_multinomial_pdf(n, &probabilities, &counts),
+                _ => Ok(String::from(
+                    "n and the counts must be integers without a decimal. Please try again.",
+                )),
+            }
+        }
+        "Exponential Pdf" => get_exp_pdf(input[0].parse::<f64>()?, input[1].parse::<f64>()?),
+        "Exponential Cdf" => match Range::parse_bounds(&input[0], &input[1]) {
+            Some(range) => get_exp_cdf(range, input[2].parse::<f64>()?),
+            None => Ok(String::from(BOUNDS_MESSAGE)),
+        },
+        "Inverse Exponential" => get_inv_exp(input[0].parse::<f64>()?, input[1].parse::<f64>()?),
+        "Uniform Pdf" => get_uniform_pdf(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+        ),
+        "Uniform Cdf" => match Range::parse_bounds(&input[0], &input[1]) {
+            Some(range) => {
+                get_uniform_cdf(range, input[2].parse::<f64>()?, input[3].parse::<f64>()?)
+            }
+            None => Ok(String::from(BOUNDS_MESSAGE)),
+        },
+        "Inverse Uniform" => get_inv_uniform(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+        ),
+        "Gamma Pdf" => get_gamma_pdf(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+        ),
+        "Gamma Cdf" => match Range::parse_bounds(&input[0], &input[1]) {
+            Some(range) => get_gamma_cdf(range, input[2].parse::<f64>()?, input[3].parse::<f64>()?),
+            None => Ok(String::from(BOUNDS_MESSAGE)),
+        },
+        "Inverse Gamma" => get_inv_gamma(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+        ),
+        "Beta Pdf" => get_beta_pdf(
             input[0].parse::<f64>()?,
             input[1].parse::<f64>()?,
             input[2].parse::<f64>()?,
         ),
+        "Beta Cdf" => match Range::parse_bounds(&input[0], &input[1]) {
+            Some(range) => get_beta_cdf(range, input[2].parse::<f64>()?, input[3].parse::<f64>()?),
+            None => Ok(String::from(BOUNDS_MESSAGE)),
+        },
+        "Inverse Beta" => get_inv_beta(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+        ),
+        "Lognormal Pdf" => get_lognormal_pdf(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+        ),
+        "Lognormal Cdf" => match Range::parse_bounds(&input[0], &input[1]) {
+            Some(range) => {
+                get_lognormal_cdf(range, input[2].parse::<f64>()?, input[3].parse::<f64>()?)
+            }
+            None => Ok(String::from(BOUNDS_MESSAGE)),
+        },
+        "Inverse Lognormal" => get_inv_lognormal(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+        ),
+        "Weibull Pdf" => get_weibull_pdf(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+        ),
+        "Weibull Cdf" => match Range::parse_bounds(&input[0], &input[1]) {
+            Some(range) => {
+                get_weibull_cdf(range, input[2].parse::<f64>()?, input[3].parse::<f64>()?)
+            }
+            None => Ok(String::from(BOUNDS_MESSAGE)),
+        },
+        "Inverse Weibull" => get_inv_weibull(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+        ),
+        "Cauchy Pdf" => get_cauchy_pdf(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+        ),
+        "Cauchy Cdf" => match Range::parse_bounds(&input[0], &input[1]) {
+            Some(range) => {
+                get_cauchy_cdf(range, input[2].parse::<f64>()?, input[3].parse::<f64>()?)
+            }
+            None => Ok(String::from(BOUNDS_MESSAGE)),
+        },
+        "Inverse Cauchy" => get_inv_cauchy(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+        ),
+        "Logistic Pdf" => get_logistic_pdf(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+        ),
+        "Logistic Cdf" => match Range::parse_bounds(&input[0], &input[1]) {
+            Some(range) => {
+                get_logistic_cdf(range, input[2].parse::<f64>()?, input[3].parse::<f64>()?)
+            }
+            None => Ok(String::from(BOUNDS_MESSAGE)),
+        },
+        "Inverse Logistic" => get_inv_logistic(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+        ),
+        "Scaled t Pdf" => get_scaled_t_pdf(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+            input[3].parse::<f64>()?,
+        ),
+        "Scaled t Cdf" => match Range::parse_bounds(&input[0], &input[1]) {
+            Some(range) => get_scaled_t_cdf(
+                range,
+                input[2].parse::<f64>()?,
+                input[3].parse::<f64>()?,
+                input[4].parse::<f64>()?,
+            ),
+            None => Ok(String::from(BOUNDS_MESSAGE)),
+        },
+        "Inverse Scaled t" => get_inv_scaled_t(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+            input[3].parse::<f64>()?,
+        ),
+
+        // Random Formulas
+        "randInt" => match (
+            input[0].parse::<i64>(),
+            input[1].parse::<i64>(),
+            input[2].parse::<u64>(),
+            parse_seed(&input[3]),
+        ) {
+            (Ok(min), Ok(max), Ok(size), Some(seed)) => get_rand_int(min, max, size, seed),
+            _ => Ok(String::from(
+                "a, b, the sample size and seed must be whole numbers. Please try again.",
+            )),
+        },
+        "randNorm" => match (input[2].parse::<u64>(), parse_seed(&input[3])) {
+            (Ok(size), Some(seed)) => get_rand_norm(
+                input[0].parse::<f64>()?,
+                input[1].parse::<f64>()?,
+                size,
+                seed,
+            ),
+            _ => Ok(String::from(SAMPLE_MESSAGE)),
+        },
+        "randBin" => match (
+            input[0].parse::<u64>(),
+            input[2].parse::<u64>(),
+            parse_seed(&input[3]),
+        ) {
+            (Ok(n), Ok(size), Some(seed)) => get_rand_bin(n, input[1].parse::<f64>()?, size, seed),
+            _ => Ok(String::from(
+                "n, the sample size and seed must be whole numbers. Please try again.",
+            )),
+        },
+        "Random Sample" => {
+            let parameters = input[1]
+                .split(',')
+                .map(|x| x.trim().parse::<f64>())
+                .collect::<Result<Vec<f64>, _>>()?;
+
+            match (input[2].parse::<u64>(), parse_seed(&input[3])) {
+                (Ok(size), Some(seed)) => get_random_sample(&input[0], &parameters, size, seed),
+                _ => Ok(String::from(SAMPLE_MESSAGE)),
+            }
+        }
+        // Runs as an animation once submitted, until then the output says what is drawn
+        "Sampling Distribution" => match Simulation::from_inputs(input) {
+            Ok(simulation) => Ok(format!(
+                "Press Enter on the last input to draw {} samples of {} from {} and watch \
+                the distribution of {} take shape.",
+                MAX_SAMPLES, simulation.sample_size, simulation.name, simulation.statistic
+            )),
+            Err(message) => Ok(message),
+        },
 
         // Intervals Formulas
         "z Interval" => app.get_z_interval(
@@ -242,8 +757,84 @@ fn match_formula_equations(
             input[2].parse::<f64>()? - 1.0,
             input[3].parse::<f64>()? / 100.0,
         ),
+        "Paired t Interval" => get_paired_t_interval(
+            &parse_list(&input[0])?,
+            &parse_list(&input[1])?,
+            input[2].parse::<f64>()? / 100.0,
+        ),
+        "χ2 Variance Interval" => get_chi_square_variance_interval(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()? / 100.0,
+        ),
+        "2-Sample F Interval" => get_2_samp_f_interval(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+            input[3].parse::<f64>()?,
+            input[4].parse::<f64>()? / 100.0,
+        ),
+        // Runs in the background once submitted, until then the output says what is drawn
+        BOOTSTRAP => match Bootstrap::from_inputs(input) {
+            Ok(bootstrap) => Ok(format!(
+                "Press Enter on the last input to draw {} resamples.",
+                bootstrap.resamples
+            )),
+            Err(message) => Ok(message),
+        },
 
         // Tests Formulas
+        "Permutation Test" => match (input[4].parse::<u64>(), parse_seed(&input[5])) {
+            (Ok(permutations), Some(seed)) => get_permutation_test(
+                &input[0],
+                &parse_list(&input[1])?,
+                &parse_list(&input[2])?,
+                &input[3],
+                permutations,
+                seed,
+            ),
+            _ => Ok(String::from(
+                "R and the seed must be whole numbers. Please try again.",
+            )),
+        },
+        "Paired t Test" => get_paired_t_test(
+            &parse_list(&input[0])?,
+            &parse_list(&input[1])?,
+            input[2].parse::<f64>()?,
+            &input[3],
+        ),
+        "Sign Test" => get_sign_test(&parse_list(&input[0])?, input[1].parse::<f64>()?, &input[2]),
+        "Wilcoxon Signed-Rank Test" => {
+            get_signed_rank_test(&parse_list(&input[0])?, input[1].parse::<f64>()?, &input[2])
+        }
+        "Mann-Whitney U Test" => {
+            get_mann_whitney_test(&parse_list(&input[0])?, &parse_list(&input[1])?, &input[2])
+        }
+        "Kruskal-Wallis Test" => get_kruskal_wallis_test(&parse_lists(&input[0])?),
+        "Friedman Test" => get_friedman_test(&parse_lists(&input[0])?),
+        "Shapiro-Wilk Test" => get_shapiro_wilk(&parse_list(&input[0])?),
+        "Anderson-Darling Test" => get_anderson_darling(&parse_list(&input[0])?),
+        "Kolmogorov-Smirnov Test" => match (input[1].as_str(), input[2].as_str()) {
+            ("", "") => get_kolmogorov_smirnov(&parse_list(&input[0])?, None),
+            (mean, std_dev) => match (mean.parse::<f64>(), std_dev.parse::<f64>()) {
+                (Ok(mean), Ok(std_dev)) => {
+                    get_kolmogorov_smirnov(&parse_list(&input[0])?, Some((mean, std_dev)))
+                }
+                _ => Ok(String::from(
+                    "Enter both µ and σ as numbers, or leave both blank to fit them.",
+                )),
+            },
+        },
+        "Jarque-Bera Test" => get_jarque_bera(&parse_list(&input[0])?),
+        "χ2 Variance Test" => get_chi_square_variance_test(
+            input[0].parse::<f64>()?,
+            input[1].parse::<f64>()?,
+            input[2].parse::<f64>()?,
+            &input[3],
+        ),
+        "Levene Test" => get_levene_test(&parse_lists(&input[0])?, Center::Mean),
+        "Brown-Forsythe Test" => get_levene_test(&parse_lists(&input[0])?, Center::Median),
+        "Bartlett Test" => get_bartlett_test(&parse_lists(&input[0])?),
         _ => Ok(String::from("No formula found with that name!")),
     }
 }
@@ -284,16 +875,14 @@ fn match_formula_inputs(formula_name: &str) -> Vec<String> {
             String::from("Upper Bound"),
             String::from("Deg of Freedom, df"),
         ],
-        // TODO: Can't find a proper formula for this yet
-        // "Inverse t" => vec![String::from("Area"), String::from("Deg of Freedom, df")],
+        "Inverse t" => vec![String::from("Area"), String::from("Deg of Freedom, df")],
         "χ2 Pdf" => vec![String::from("x"), String::from("Deg of Freedom, df")],
         "χ2 Cdf" => vec![
             String::from("Lower Bound"),
             String::from("Upper Bound"),
             String::from("Deg of Freedom, df"),
         ],
-        // TODO: Can't find a proper formula for this yet
-        // "Inverse χ2" => vec![String::from("Area"), String::from("Deg of Freedom, df")],
+        "Inverse χ2" => vec![String::from("Area"), String::from("Deg of Freedom, df")],
         "Binomial Pdf" => vec![
             String::from("Num Trials, n"),
             String::from("Prob Success, p"),
@@ -302,6 +891,7 @@ fn match_formula_inputs(formula_name: &str) -> Vec<String> {
         "Binomial Cdf" => vec![
             String::from("Num Trials, n"),
             String::from("Prob Success, p"),
+            String::from("Range"),
             String::from("Lower Bound"),
             String::from("Upper Bound"),
         ],
@@ -319,14 +909,253 @@ fn match_formula_inputs(formula_name: &str) -> Vec<String> {
         "Geometric Pdf" => vec![String::from("Prob Success, p"), String::from("x")],
         "Geometric Cdf" => vec![
             String::from("Prob Success, p"),
+            String::from("Range"),
             String::from("Lower Bound"),
             String::from("Upper Bound"),
         ],
         "Poisson Pdf" => vec![String::from("λ"), String::from("x")],
         "Poisson Cdf" => vec![
             String::from("λ"),
+            String::from("Range"),
+            String::from("Lower Bound"),
+            String::from("Upper Bound"),
+        ],
+        "Hypergeometric Pdf" => vec![
+            String::from("Population, N"),
+            String::from("Successes in Pop, K"),
+            String::from("Draws, n"),
+            String::from("x"),
+        ],
+        "Hypergeometric Cdf" => vec![
+            String::from("Population, N"),
+            String::from("Successes in Pop, K"),
+            String::from("Draws, n"),
+            String::from("Range"),
+            String::from("Lower Bound"),
+            String::from("Upper Bound"),
+        ],
+        "Inverse Hypergeometric" => vec![
+            String::from("Area"),
+            String::from("Population, N"),
+            String::from("Successes in Pop, K"),
+            String::from("Draws, n"),
+        ],
+        "Negative Binomial Pdf" => vec![
+            String::from("Successes Needed, r"),
+            String::from("Prob Success, p"),
+            String::from("Failures, x"),
+        ],
+        "Negative Binomial Cdf" => vec![
+            String::from("Successes Needed, r"),
+            String::from("Prob Success, p"),
+            String::from("Range"),
+            String::from("Lower Bound"),
+            String::from("Upper Bound"),
+        ],
+        "Inverse Negative Binomial" => vec![
+            String::from("Area"),
+            String::from("Successes Needed, r"),
+            String::from("Prob Success, p"),
+        ],
+        "Discrete Uniform Pdf" => vec![
+            String::from("Minimum, a"),
+            String::from("Maximum, b"),
+            String::from("x"),
+        ],
+        "Discrete Uniform Cdf" => vec![
+            String::from("Minimum, a"),
+            String::from("Maximum, b"),
+            String::from("Range"),
+            String::from("Lower Bound"),
+            String::from("Upper Bound"),
+        ],
+        "Inverse Discrete Uniform" => vec![
+            String::from("Area"),
+            String::from("Minimum, a"),
+            String::from("Maximum, b"),
+        ],
+        "Bernoulli Pdf" => vec![String::from("Prob Success, p"), String::from("x")],
+        "Bernoulli Cdf" => vec![
+            String::from("Prob Success, p"),
+            String::from("Range"),
+            String::from("Lower Bound"),
+            String::from("Upper Bound"),
+        ],
+        "Inverse Bernoulli" => vec![String::from("Area"), String::from("Prob Success, p")],
+        "Multinomial Pdf" => vec![
+            String::from("Num Trials, n"),
+            String::from("Probability List"),
+            String::from("Count List"),
+        ],
+        "Exponential Pdf" => vec![String::from("x"), String::from("Rate, λ")],
+        "Exponential Cdf" => vec![
+            String::from("Lower Bound"),
+            String::from("Upper Bound"),
+            String::from("Rate, λ"),
+        ],
+        "Inverse Exponential" => vec![String::from("Area"), String::from("Rate, λ")],
+        "Uniform Pdf" => vec![
+            String::from("x"),
+            String::from("Minimum, a"),
+            String::from("Maximum, b"),
+        ],
+        "Uniform Cdf" => vec![
+            String::from("Lower Bound"),
+            String::from("Upper Bound"),
+            String::from("Minimum, a"),
+            String::from("Maximum, b"),
+        ],
+        "Inverse Uniform" => vec![
+            String::from("Area"),
+            String::from("Minimum, a"),
+            String::from("Maximum, b"),
+        ],
+        "Gamma Pdf" => vec![
+            String::from("x"),
+            String::from("Shape, α"),
+            String::from("Rate, β"),
+        ],
+        "Gamma Cdf" => vec![
+            String::from("Lower Bound"),
+            String::from("Upper Bound"),
+            String::from("Shape, α"),
+            String::from("Rate, β"),
+        ],
+        "Inverse Gamma" => vec![
+            String::from("Area"),
+            String::from("Shape, α"),
+            String::from("Rate, β"),
+        ],
+        "Beta Pdf" => vec![
+            String::from("x"),
+            String::from("Shape, α"),
+            String::from("Shape, β"),
+        ],
+        "Beta Cdf" => vec![
+            String::from("Lower Bound"),
+            String::from("Upper Bound"),
+            String::from("Shape, α"),
+            String::from("Shape, β"),
+        ],
+        "Inverse Beta" => vec![
+            String::from("Area"),
+            String::from("Shape, α"),
+            String::from("Shape, β"),
+        ],
+        "Lognormal Pdf" => vec![
+            String::from("x"),
+            String::from("µ of ln X"),
+            String::from("σ of ln X"),
+        ],
+        "Lognormal Cdf" => vec![
+            String::from("Lower Bound"),
+            String::from("Upper Bound"),
+            String::from("µ of ln X"),
+            String::from("σ of ln X"),
+        ],
+        "Inverse Lognormal" => vec![
+            String::from("Area"),
+            String::from("µ of ln X"),
+            String::from("σ of ln X"),
+        ],
+        "Weibull Pdf" => vec![
+            String::from("x"),
+            String::from("Shape, k"),
+            String::from("Scale, λ"),
+        ],
+        "Weibull Cdf" => vec![
+            String::from("Lower Bound"),
+            String::from("Upper Bound"),
+            String::from("Shape, k"),
+            String::from("Scale, λ"),
+        ],
+        "Inverse Weibull" => vec![
+            String::from("Area"),
+            String::from("Shape, k"),
+            String::from("Scale, λ"),
+        ],
+        "Cauchy Pdf" => vec![
+            String::from("x"),
+            String::from("Location, x0"),
+            String::from("Scale, γ"),
+        ],
+        "Cauchy Cdf" => vec![
+            String::from("Lower Bound"),
+            String::from("Upper Bound"),
+            String::from("Location, x0"),
+            String::from("Scale, γ"),
+        ],
+        "Inverse Cauchy" => vec![
+            String::from("Area"),
+            String::from("Location, x0"),
+            String::from("Scale, γ"),
+        ],
+        "Logistic Pdf" => vec![
+            String::from("x"),
+            String::from("µ"),
+            String::from("Scale, s"),
+        ],
+        "Logistic Cdf" => vec![
             String::from("Lower Bound"),
             String::from("Upper Bound"),
+            String::from("µ"),
+            String::from("Scale, s"),
+        ],
+        "Inverse Logistic" => vec![
+            String::from("Area"),
+            String::from("µ"),
+            String::from("Scale, s"),
+        ],
+        "Scaled t Pdf" => vec![
+            String::from("x"),
+            String::from("µ"),
+            String::from("Scale, s"),
+            String::from("Deg of Freedom, df"),
+        ],
+        "Scaled t Cdf" => vec![
+            String::from("Lower Bound"),
+            String::from("Upper Bound"),
+            String::from("µ"),
+            String::from("Scale, s"),
+            String::from("Deg of Freedom, df"),
+        ],
+        "Inverse Scaled t" => vec![
+            String::from("Area"),
+            String::from("µ"),
+            String::from("Scale, s"),
+            String::from("Deg of Freedom, df"),
+        ],
+
+        // Random Formulas
+        "randInt" => vec![
+            String::from("Minimum, a"),
+            String::from("Maximum, b"),
+            String::from("Sample Size"),
+            String::from("Seed"),
+        ],
+        "randNorm" => vec![
+            String::from("µ"),
+            String::from("σ"),
+            String::from("Sample Size"),
+            String::from("Seed"),
+        ],
+        "randBin" => vec![
+            String::from("Num Trials, n"),
+            String::from("Prob Success, p"),
+            String::from("Sample Size"),
+            String::from("Seed"),
+        ],
+        "Random Sample" => vec![
+            String::from("Distribution"),
+            String::from("Parameter List"),
+            String::from("Sample Size"),
+            String::from("Seed"),
+        ],
+        "Sampling Distribution" => vec![
+            String::from("Distribution"),
+            String::from("Parameter List"),
+            String::from("Size of Each Sample"),
+            String::from("Seed"),
         ],
 
         // Intervals Formulas
@@ -372,6 +1201,31 @@ fn match_formula_inputs(formula_name: &str) -> Vec<String> {
             String::from("n2"),
             String::from("C Level"),
         ],
+        "Paired t Interval" => vec![
+            String::from("Before List"),
+            String::from("After List"),
+            String::from("C Level"),
+        ],
+        "χ2 Variance Interval" => vec![
+            String::from("Sx"),
+            String::from("n"),
+            String::from("C Level"),
+        ],
+        "2-Sample F Interval" => vec![
+            String::from("Sx1"),
+            String::from("n1"),
+            String::from("Sx2"),
+            String::from("n2"),
+            String::from("C Level"),
+        ],
+        BOOTSTRAP => vec![
+            String::from("Statistic"),
+            String::from("Data List"),
+            String::from("Second List"),
+            String::from("Resamples, B"),
+            String::from("C Level"),
+            String::from("Seed"),
+        ],
 
         // Tests Formulas
         "z Test" => vec![
@@ -437,6 +1291,49 @@ fn match_formula_inputs(formula_name: &str) -> Vec<String> {
             String::from("Group - {x̄}"),
             String::from("Group - Sx"),
         ],
+        "Permutation Test" => vec![
+            String::from("Compare"),
+            String::from("Group 1 List"),
+            String::from("Group 2 List"),
+            String::from("Alternate Hyp"),
+            String::from("Permutations, R"),
+            String::from("Seed"),
+        ],
+        "Paired t Test" => vec![
+            String::from("Before List"),
+            String::from("After List"),
+            String::from("µ0"),
+            String::from("Alternate Hyp"),
+        ],
+        "Sign Test" | "Wilcoxon Signed-Rank Test" => vec![
+            String::from("Data List"),
+            String::from("Median, M0"),
+            String::from("Alternate Hyp"),
+        ],
+        "Mann-Whitney U Test" => vec![
+            String::from("Group 1 List"),
+            String::from("Group 2 List"),
+            String::from("Alternate Hyp"),
+        ],
+        "Kruskal-Wallis Test" => vec![String::from("Group Lists")],
+        "Friedman Test" => vec![String::from("Data Matrix")],
+        "Shapiro-Wilk Test" | "Anderson-Darling Test" | "Jarque-Bera Test" => {
+            vec![String::from("Data List")]
+        }
+        "Kolmogorov-Smirnov Test" => vec![
+            String::from("Data List"),
+            String::from("Normal µ"),
+            String::from("Normal σ"),
+        ],
+        "χ2 Variance Test" => vec![
+            String::from("σ0"),
+            String::from("Sx"),
+            String::from("n"),
+            String::from("Alternate Hyp"),
+        ],
+        "Levene Test" | "Brown-Forsythe Test" | "Bartlett Test" => {
+            vec![String::from("Group Lists")]
+        }
 
         // Regressions Formulas
         "Linear Regression (mx+b)" => vec![String::from("X List"), String::from("Y List")],
@@ -449,3 +1346,131 @@ fn match_formula_inputs(formula_name: &str) -> Vec<String> {
         _ => vec![String::from("No formula found with that name!")],
     }
 }
+
+fn match_formula_aliases(formula_name: &str) -> Vec<&'static str> {
+    match formula_name {
+        // Probability Formulas
+        "Factorial (!)" => vec!["factorial", "fact"],
+        "Permutations" => vec!["npr", "permutation"],
+        "Combinations" => vec!["ncr", "combination", "choose"],
+        "Normal Pdf" => vec!["normalpdf", "normpdf"],
+        "Normal Cdf" => vec!["normalcdf", "normcdf"],
+        "Inverse Normal" => vec!["invnorm", "norm ppf", "z score"],
+        "t Pdf" => vec!["tpdf", "student t pdf"],
+        "t Cdf" => vec!["tcdf", "student t cdf"],
+        "Inverse t" => vec!["invt", "t score", "t quantile"],
+        "χ2 Pdf" => vec!["chisqpdf", "chi square pdf", "chi2 pdf"],
+        "χ2 Cdf" => vec!["chisqcdf", "chi square cdf", "chi2 cdf"],
+        "Inverse χ2" => vec!["invchisq", "chi square quantile", "chi2 quantile"],
+        "Binomial Pdf" => vec!["binompdf", "binomial pmf"],
+        "Binomial Cdf" => vec!["binomcdf"],
+        "F Pdf" => vec!["fpdf"],
+        "F Cdf" => vec!["fcdf"],
+        "Geometric Pdf" => vec!["geometpdf", "geometric pmf"],
+        "Geometric Cdf" => vec!["geometcdf"],
+        "Poisson Pdf" => vec!["poissonpdf", "poisson pmf"],
+        "Poisson Cdf" => vec!["poissoncdf"],
+        "Hypergeometric Pdf" => vec!["hypergeompdf", "hypergeometric pmf"],
+        "Hypergeometric Cdf" => vec!["hypergeomcdf"],
+        "Inverse Hypergeometric" => vec!["invhypergeom", "hypergeometric quantile"],
+        "Negative Binomial Pdf" => vec!["nbinpdf", "negative binomial pmf", "pascal"],
+        "Negative Binomial Cdf" => vec!["nbincdf"],
+        "Inverse Negative Binomial" => vec!["invnbin", "negative binomial quantile"],
+        "Discrete Uniform Pdf" => vec!["unidpdf", "discrete uniform pmf", "dice"],
+        "Discrete Uniform Cdf" => vec!["unidcdf"],
+        "Inverse Discrete Uniform" => vec!["invunid", "discrete uniform quantile"],
+        "Bernoulli Pdf" => vec!["bernpdf", "bernoulli pmf"],
+        "Bernoulli Cdf" => vec!["berncdf"],
+        "Inverse Bernoulli" => vec!["invbern", "bernoulli quantile"],
+        "Multinomial Pdf" => vec!["mnpdf", "multinomial pmf"],
+        "Exponential Pdf" => vec!["exppdf", "exponential pdf"],
+        "Exponential Cdf" => vec!["expcdf", "exponential cdf"],
+        "Inverse Exponential" => vec!["invexp", "exponential quantile"],
+        "Uniform Pdf" => vec!["unifpdf", "uniform pdf"],
+        "Uniform Cdf" => vec!["unifcdf", "uniform cdf"],
+        "Inverse Uniform" => vec!["invunif", "uniform quantile"],
+        "Gamma Pdf" => vec!["gammapdf"],
+        "Gamma Cdf" => vec!["gammacdf"],
+        "Inverse Gamma" => vec!["invgamma", "gamma quantile"],
+        "Beta Pdf" => vec!["betapdf"],
+        "Beta Cdf" => vec!["betacdf"],
+        "Inverse Beta" => vec!["invbeta", "beta quantile"],
+        "Lognormal Pdf" => vec!["lognpdf", "log normal pdf"],
+        "Lognormal Cdf" => vec!["logncdf", "log normal cdf"],
+        "Inverse Lognormal" => vec!["invlogn", "log normal quantile"],
+        "Weibull Pdf" => vec!["wblpdf", "weibull pdf"],
+        "Weibull Cdf" => vec!["wblcdf", "weibull cdf"],
+        "Inverse Weibull" => vec!["invwbl", "weibull quantile"],
+        "Cauchy Pdf" => vec!["cauchypdf"],
+        "Cauchy Cdf" => vec!["cauchycdf"],
+        "Inverse Cauchy" => vec!["invcauchy", "cauchy quantile"],
+        "Logistic Pdf" => vec!["logisticpdf", "dlogis"],
+        "Logistic Cdf" => vec!["logisticcdf", "plogis"],
+        "Inverse Logistic" => vec!["invlogistic", "qlogis"],
+        "Scaled t Pdf" => vec!["location scale t pdf"],
+        "Scaled t Cdf" => vec!["location scale t cdf"],
+        "Inverse Scaled t" => vec!["location scale t quantile"],
+
+        // Random Formulas
+        "randInt" => vec!["random integer", "randint"],
+        "randNorm" => vec!["random normal", "rnorm"],
+        "randBin" => vec!["random binomial", "rbinom"],
+        "Random Sample" => vec!["simulate", "random draw", "generate data"],
+        "Sampling Distribution" => {
+            vec!["clt", "central limit theorem", "sample means", "simulation"]
+        }
+
+        // Intervals Formulas
+        "z Interval" => vec!["zinterval", "confidence interval z"],
+        "t Interval" => vec!["tinterval", "confidence interval t"],
+        "2-Sample z Interval" => vec!["2sampzint", "two sample z interval"],
+        "2-Sample t Interval" => vec!["2samptint", "two sample t interval"],
+        "1-Prop z Interval" => vec!["1propzint", "proportion interval"],
+        "2-Prop z Interval" => vec!["2propzint", "two proportion interval"],
+        "χ2 Variance Interval" => vec!["variance interval", "sigma interval", "chi2 interval"],
+        "2-Sample F Interval" => vec!["2sampfint", "variance ratio interval"],
+        BOOTSTRAP => vec![
+            "bootstrap",
+            "resampling interval",
+            "bca",
+            "percentile interval",
+        ],
+
+        // Tests Formulas
+        "z Test" => vec!["ztest"],
+        "t Test" => vec!["ttest"],
+        "2-Sample z Test" => vec!["2sampztest", "two sample z test"],
+        "2-Sample t Test" => vec!["2samptest", "two sample t test"],
+        "1-Prop z Test" => vec!["1propztest", "proportion test"],
+        "2-Prop z Test" => vec!["2propztest", "two proportion test"],
+        "χ2 GOF" => vec!["chisq gof", "chi square goodness of fit", "chi2 gof"],
+        "χ2 2-way Test" => vec!["chisq test", "chi square independence", "chi2 2 way"],
+        "2-Sample F Test" => vec!["2sampftest", "variance ratio test"],
+        "ANOVA" => vec!["anova", "one way anova"],
+        "Permutation Test" => vec!["randomization test", "shuffle test", "exact test"],
+        "Paired t Test" => vec!["paired test", "before after test", "dependent t test"],
+        "Sign Test" => vec!["signtest", "median test"],
+        "Wilcoxon Signed-Rank Test" => vec!["wilcoxon", "signed rank", "signrank"],
+        "Mann-Whitney U Test" => vec!["wilcoxon rank sum", "mann whitney", "utest"],
+        "Kruskal-Wallis Test" => vec!["kruskal", "nonparametric anova"],
+        "Friedman Test" => vec!["friedman", "repeated measures ranks"],
+        "Shapiro-Wilk Test" => vec!["shapiro", "swtest", "normality test"],
+        "Anderson-Darling Test" => vec!["adtest", "anderson darling", "normality test"],
+        "Kolmogorov-Smirnov Test" => vec!["kstest", "lilliefors", "normality test"],
+        "Jarque-Bera Test" => vec!["jbtest", "skewness kurtosis test", "normality test"],
+        "χ2 Variance Test" => vec!["variance test", "sigma test", "chi2 variance"],
+        "Levene Test" => vec!["levene", "equal variances", "homogeneity of variance"],
+        "Brown-Forsythe Test" => vec!["brown forsythe", "median levene", "equal variances"],
+        "Bartlett Test" => vec!["bartlett", "equal variances", "homogeneity of variance"],
+
+        // Regressions Formulas
+        "Linear Regression (mx+b)" => vec!["linreg", "linregmx", "least squares"],
+        "Linear Regression (a+bx)" => vec!["linreg", "linregbx", "least squares"],
+        "Median-Median Line" => vec!["medmed"],
+        "Quadratic Regression" => vec!["quadreg"],
+        "Cubic Regression" => vec!["cubicreg"],
+        "Quartic Regression" => vec!["quartreg"],
+
+        _ => vec![],
+    }
+}
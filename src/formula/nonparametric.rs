@@ -0,0 +1,487 @@
+use super::hypothesis::{chi_square_sf, normal_cdf, normal_sf, Alternative, ALTERNATIVE_MESSAGE};
+use super::probability::format_probability;
+use super::statistics::{ranks, ties};
+
+use {
+    anyhow::Result,
+    statrs::distribution::{Binomial, Univariate},
+};
+
+/// Largest sample the exact signed-rank and Mann-Whitney distributions are used for, their
+/// counts stay exact in an f64 up to here
+const EXACT_LIMIT: usize = 50;
+
+/// Most arrangements of the ranks enumerated for an exact Kruskal-Wallis or Friedman test
+const MAX_ARRANGEMENTS: f64 = 100_000.0;
+
+/// Tests a median against M0 by counting values above and below it, values equal to M0 are
+/// dropped
+pub fn get_sign_test(data: &[f64], median: f64, alternative: &str) -> Result<String> {
+    let alternative = match Alternative::parse(alternative) {
+        Some(alternative) => alternative,
+        None => return Ok(String::from(ALTERNATIVE_MESSAGE)),
+    };
+
+    let above = data.iter().filter(|&&x| x > median).count() as u64;
+    let below = data.iter().filter(|&&x| x < median).count() as u64;
+    let n = above + below;
+    if n == 0 {
+        return Ok(String::from("Ensure some values differ from M0"));
+    }
+
+    let binomial = Binomial::new(0.5, n)?;
+    let lower = binomial.cdf(above as f64);
+    let upper = if above == 0 {
+        1.0
+    } else {
+        1.0 - binomial.cdf(above as f64 - 1.0)
+    };
+
+    Ok(format!(
+        "Above M0: {}\nBelow M0: {}\nDropped: {}\nP Value: {}\nMethod: Exact binomial",
+        above,
+        below,
+        data.len() as u64 - n,
+        format_probability(alternative.p_value(lower, upper))
+    ))
+}
+
+/// Tests a median against M0 with the ranks of the distances from it, for symmetric data
+pub fn get_signed_rank_test(data: &[f64], median: f64, alternative: &str) -> Result<String> {
+    let alternative = match Alternative::parse(alternative) {
+        Some(alternative) => alternative,
+        None => return Ok(String::from(ALTERNATIVE_MESSAGE)),
+    };
+
+    let differences: Vec<f64> = data
+        .iter()
+        .map(|x| x - median)
+        .filter(|&d| d != 0.0)
+        .collect();
+    let n = differences.len();
+    if n == 0 {
+        return Ok(String::from("Ensure some values differ from M0"));
+    }
+
+    let distances: Vec<f64> = differences.iter().map(|d| d.abs()).collect();
+    let w_plus: f64 = ranks(&distances)
+        .iter()
+        .zip(&differences)
+        .filter(|(_, &d)| d > 0.0)
+        .map(|(rank, _)| rank)
+        .sum();
+    let tied = ties(&distances);
+
+    let output = format!("W+: {}\nn: {}\n", w_plus, n);
+    if tied == 0.0 && n <= EXACT_LIMIT {
+        // W+ is the sum of a random subset of the ranks 1 to n
+        let counts = (1..=n).fold(vec![1.0], |counts, rank| {
+            let mut next = counts.clone();
+            next.resize(counts.len() + rank, 0.0);
+            for (sum, count) in counts.iter().enumerate() {
+                next[sum + rank] += count;
+            }
+            next
+        });
+        let (lower, upper) = exact_tails(&counts, w_plus as usize);
+
+        return Ok(format!(
+            "{}P Value: {}\nMethod: Exact",
+            output,
+            format_probability(alternative.p_value(lower, upper))
+        ));
+    }
+
+    let n = n as f64;
+    let mean = n * (n + 1.0) / 4.0;
+    let variance = n * (n + 1.0) * (2.0 * n + 1.0) / 24.0 - tied / 48.0;
+    Ok(format!(
+        "{}{}",
+        output,
+        normal_approximation(w_plus, mean, variance, alternative)
+    ))
+}
+
+/// Tests whether values from one group tend to be larger than from the other, with the
+/// ranks of both groups together
+pub fn get_mann_whitney_test(
+    group_1: &[f64],
+    group_2: &[f64],
+    alternative: &str,
+) -> Result<String> {
+    let alternative = match Alternative::parse(alternative) {
+        Some(alternative) => alternative,
+        None => return Ok(String::from(ALTERNATIVE_MESSAGE)),
+    };
+    if group_1.is_empty() || group_2.is_empty() {
+        return Ok(String::from("Ensure both groups have at least one value"));
+    }
+
+    let pooled: Vec<f64> = group_1.iter().chain(group_2).copied().collect();
+    let (n_1, n_2) = (group_1.len(), group_2.len());
+    let rank_sum: f64 = ranks(&pooled)[..n_1].iter().sum();
+    // Pairs with the group 1 value larger, ties counting half
+    let u = rank_sum - (n_1 * (n_1 + 1)) as f64 / 2.0;
+    let tied = ties(&pooled);
+
+    let output = format!("U: {}\nRank Sum 1: {}\n", u, rank_sum);
+    if tied == 0.0 && pooled.len() <= EXACT_LIMIT {
+        // U counts the inversions of a random ordering of the groups, its distribution is the
+        // Gaussian binomial coefficient ∏ (1 - qⁿ²⁺ⁱ) / (1 - qⁱ) for i from 1 to n1
+        let mut counts = vec![0.0; n_1 * n_2 + 1];
+        counts[0] = 1.0;
+        for i in 1..=n_1 {
+            for k in (n_2 + i..counts.len()).rev() {
+                counts[k] -= counts[k - n_2 - i];
+            }
+            for k in i..counts.len() {
+                counts[k] += counts[k - i];
+            }
+        }
+        let (lower, upper) = exact_tails(&counts, u as usize);
+
+        return Ok(format!(
+            "{}P Value: {}\nMethod: Exact",
+            output,
+            format_probability(alternative.p_value(lower, upper))
+        ));
+    }
+
+    let (n_1, n_2, n) = (n_1 as f64, n_2 as f64, pooled.len() as f64);
+    let mean = n_1 * n_2 / 2.0;
+    let variance = n_1 * n_2 / 12.0 * ((n + 1.0) - tied / (n * (n - 1.0)));
+    Ok(format!(
+        "{}{}",
+        output,
+        normal_approximation(u, mean, variance, alternative)
+    ))
+}
+
+/// Tests whether several groups come from the same distribution, with the ranks of all of
+/// them together
+pub fn get_kruskal_wallis_test(groups: &[Vec<f64>]) -> Result<String> {
+    if groups.len() < 2 || groups.iter().any(|group| group.is_empty()) {
+        return Ok(String::from(
+            "Ensure there are at least 2 groups, each with at least one value",
+        ));
+    }
+
+    let pooled: Vec<f64> = groups.iter().flatten().copied().collect();
+    let n = pooled.len() as f64;
+    let correction = 1.0 - ties(&pooled) / (n.powi(3) - n);
+    if correction <= 0.0 {
+        return Ok(String::from("Ensure not every value is the same"));
+    }
+
+    let sizes: Vec<usize> = groups.iter().map(|group| group.len()).collect();
+    let h = |sums: &[f64]| {
+        let spread: f64 = sums
+            .iter()
+            .zip(&sizes)
+            .map(|(sum, &size)| sum.powi(2) / size as f64)
+            .sum();
+        (12.0 / (n * (n + 1.0)) * spread - 3.0 * (n + 1.0)) / correction
+    };
+
+    let ranks = ranks(&pooled);
+    let mut sums = vec![0.0; groups.len()];
+    let mut start = 0;
+    for (sum, &size) in sums.iter_mut().zip(&sizes) {
+        *sum = ranks[start..start + size].iter().sum();
+        start += size;
+    }
+    let observed = h(&sums);
+    let df = groups.len() - 1;
+
+    // N! / (n1! n2! ...) ways to share the ranks between the groups
+    let arrangements = (1..=pooled.len()).map(|i| i as f64).product::<f64>()
+        / sizes
+            .iter()
+            .map(|&size| (1..=size).map(|i| i as f64).product::<f64>())
+            .product::<f64>();
+
+    let (p_value, method) = if arrangements.round() <= MAX_ARRANGEMENTS {
+        let (mut extreme, mut total) = (0.0, 0.0);
+        share_ranks(
+            &ranks,
+            &mut sizes.clone(),
+            &mut vec![0.0; sizes.len()],
+            &mut |sums| {
+                total += 1.0;
+                if Alternative::Greater.is_extreme(h(sums), observed) {
+                    extreme += 1.0;
+                }
+            },
+        );
+        (
+            extreme / total,
+            format!("Exact, all {} arrangements", total),
+        )
+    } else {
+        (
+            chi_square_sf(observed, df as f64),
+            String::from("χ2 approximation, tie corrected"),
+        )
+    };
+
+    Ok(format!(
+        "H: {}\ndf: {}\nP Value: {}\nMethod: {}",
+        observed,
+        df,
+        format_probability(p_value),
+        method
+    ))
+}
+
+/// Tests whether several treatments differ when every block (subject) gets each of them, with
+/// the ranks within each block. Rows are blocks and columns treatments.
+pub fn get_friedman_test(blocks: &[Vec<f64>]) -> Result<String> {
+    let k = blocks.first().map_or(0, |block| block.len());
+    if blocks.len() < 2 || k < 2 || blocks.iter().any(|block| block.len() != k) {
+        return Ok(String::from(
+            "Ensure there are at least 2 rows, each with the same number of at least 2 values",
+        ));
+    }
+
+    let rows: Vec<Vec<f64>> = blocks.iter().map(|block| ranks(block)).collect();
+    let (n, k_f) = (rows.len() as f64, k as f64);
+    // With ties the squared ranks add up to less than without, this is what makes Q tie
+    // corrected
+    let spread =
+        rows.iter().flatten().map(|r| r.powi(2)).sum::<f64>() - n * k_f * (k_f + 1.0).powi(2) / 4.0;
+    if spread <= 0.0 {
+        return Ok(String::from("Ensure not every row is all ties"));
+    }
+
+    let q = |sums: &[f64]| {
+        (k_f - 1.0)
+            * sums
+                .iter()
+                .map(|sum| (sum - n * (k_f + 1.0) / 2.0).powi(2))
+                .sum::<f64>()
+            / spread
+    };
+
+    let mut sums = vec![0.0; k];
+    for row in &rows {
+        for (sum, rank) in sums.iter_mut().zip(row) {
+            *sum += rank;
+        }
+    }
+    let observed = q(&sums);
+    let df = k - 1;
+
+    // Counted before any are built, k! orderings of a row don't fit in memory for large k
+    let arrangements: f64 = rows.iter().map(|row| ordering_count(row)).product();
+
+    let (p_value, method) = if arrangements.round() <= MAX_ARRANGEMENTS {
+        let orderings: Vec<Vec<Vec<f64>>> = rows.iter().map(|row| orderings(row)).collect();
+        let (mut extreme, mut total) = (0.0, 0.0);
+        shuffle_rows(&orderings, &mut vec![0.0; k], &mut |sums| {
+            total += 1.0;
+            if Alternative::Greater.is_extreme(q(sums), observed) {
+                extreme += 1.0;
+            }
+        });
+        (
+            extreme / total,
+            format!("Exact, all {} arrangements", total),
+        )
+    } else {
+        (
+            chi_square_sf(observed, df as f64),
+            String::from("χ2 approximation, tie corrected"),
+        )
+    };
+
+    Ok(format!(
+        "Q: {}\ndf: {}\nP Value: {}\nMethod: {}",
+        observed,
+        df,
+        format_probability(p_value),
+        method
+    ))
+}
+
+/// P(T ≤ t) and P(T ≥ t) from the number of ways T takes each whole value
+fn exact_tails(counts: &[f64], t: usize) -> (f64, f64) {
+    let total: f64 = counts.iter().sum();
+    let lower: f64 = counts[..=t].iter().sum();
+    let upper: f64 = counts[t..].iter().sum();
+    (lower / total, upper / total)
+}
+
+/// z with a continuity correction of a half towards the mean for the tail probabilities
+fn normal_approximation(t: f64, mean: f64, variance: f64, alternative: Alternative) -> String {
+    let std_dev = variance.sqrt();
+    let lower = normal_cdf((t - mean + 0.5) / std_dev);
+    let upper = normal_sf((t - mean - 0.5) / std_dev);
+
+    format!(
+        "z: {}\nP Value: {}\nMethod: Normal approximation, tie corrected",
+        (t - mean) / std_dev,
+        format_probability(alternative.p_value(lower, upper))
+    )
+}
+
+/// Calls `visit` with the rank sums of every way to give the ranks to groups of the remaining
+/// sizes
+fn share_ranks(
+    ranks: &[f64],
+    remaining: &mut [usize],
+    sums: &mut [f64],
+    visit: &mut impl FnMut(&[f64]),
+) {
+    match ranks.split_first() {
+        None => visit(sums),
+        Some((&rank, rest)) => {
+            for group in 0..remaining.len() {
+                if remaining[group] > 0 {
+                    remaining[group] -= 1;
+                    sums[group] += rank;
+                    share_ranks(rest, remaining, sums, visit);
+                    sums[group] -= rank;
+                    remaining[group] += 1;
+                }
+            }
+        }
+    }
+}
+
+/// Calls `visit` with the column sums of every combination of one ordering per row
+fn shuffle_rows(rows: &[Vec<Vec<f64>>], sums: &mut [f64], visit: &mut impl FnMut(&[f64])) {
+    match rows.split_first() {
+        None => visit(sums),
+        Some((orderings, rest)) => {
+            for ordering in orderings {
+                for (sum, rank) in sums.iter_mut().zip(ordering) {
+                    *sum += rank;
+                }
+                shuffle_rows(rest, sums, visit);
+                for (sum, rank) in sums.iter_mut().zip(ordering) {
+                    *sum -= rank;
+                }
+            }
+        }
+    }
+}
+
+/// k! / (t1! t2! ...) distinct orderings of a row's k ranks, with ties of sizes t1, t2, ...
+fn ordering_count(row: &[f64]) -> f64 {
+    let factorial = |n: usize| (1..=n).map(|i| i as f64).product::<f64>();
+    let mut ranks = row.to_vec();
+    ranks.sort_by(f64::total_cmp);
+    ranks.dedup_by(|a, b| a == b);
+    factorial(row.len())
+        / ranks
+            .iter()
+            .map(|rank| factorial(row.iter().filter(|&r| r == rank).count()))
+            .product::<f64>()
+}
+
+/// Every distinct ordering of a row's ranks, tied ranks only once
+fn orderings(row: &[f64]) -> Vec<Vec<f64>> {
+    let mut ordering = row.to_vec();
+    ordering.sort_by(f64::total_cmp);
+    let mut orderings = vec![ordering.clone()];
+
+    // Steps through the orderings in lexicographic order until the last, descending, one
+    loop {
+        let pivot = match (0..ordering.len() - 1)
+            .rev()
+            .find(|&i| ordering[i] < ordering[i + 1])
+        {
+            Some(pivot) => pivot,
+            None => return orderings,
+        };
+        let swap = (pivot + 1..ordering.len())
+            .rev()
+            .find(|&i| ordering[i] > ordering[pivot])
+            .unwrap();
+        ordering.swap(pivot, swap);
+        ordering[pivot + 1..].reverse();
+        orderings.push(ordering.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variables::number_field;
+
+    /// Exact p values checked against enumerating every arrangement
+    #[test]
+    fn nonparametric_tests_match_their_exact_distributions() -> Result<()> {
+        let data = [1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30, 1.0];
+        let close = |output: String, expected: f64| {
+            (number_field(&output, "P Value") - expected).abs() < 1e-9
+        };
+
+        assert!(close(get_sign_test(&data, 1.0, "≠")?, 0.0390625));
+        assert!(close(get_signed_rank_test(&data, 1.0, "≠")?, 0.01171875));
+        assert!(close(get_signed_rank_test(&data, 1.0, ">")?, 0.005859375));
+
+        let group_1 = [0.8, 0.83, 1.89, 1.04, 1.45, 1.38, 1.91, 1.64, 0.73, 1.46];
+        let group_2 = [1.15, 0.88, 0.9, 0.74, 1.21];
+        let output = get_mann_whitney_test(&group_1, &group_2, ">")?;
+        assert!(output.starts_with("U: 35\n") && output.ends_with("Exact"));
+        assert!(close(output, 0.1272061272061272));
+        assert!(close(
+            get_mann_whitney_test(&group_1, &group_2, "≠")?,
+            0.2544122544122544
+        ));
+
+        let groups = [
+            vec![6.4, 6.8, 7.2, 8.3],
+            vec![2.5, 3.7, 4.9, 5.4, 5.9],
+            vec![1.3, 4.1, 4.9, 5.2],
+        ];
+        let output = get_kruskal_wallis_test(&groups)?;
+        assert!(output.contains("Exact, all 90090 arrangements"));
+        assert!(close(output, 0.007636807636807637));
+
+        let blocks = [
+            vec![7.0, 9.0, 8.0],
+            vec![6.0, 5.0, 7.0],
+            vec![9.0, 7.0, 6.0],
+            vec![8.0, 5.0, 6.0],
+            vec![6.0, 4.0, 5.0],
+        ];
+        let output = get_friedman_test(&blocks)?;
+        assert!(output.starts_with("Q: 1.6"));
+        assert!(close(output, 0.5216049382716049));
+        Ok(())
+    }
+
+    #[test]
+    fn tied_ranks_use_a_corrected_normal_approximation() -> Result<()> {
+        let group_1 = [1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 4.0, 5.0, 6.0, 6.0];
+        let group_2 = [2.0, 3.0, 4.0, 4.0, 5.0, 5.0, 6.0, 7.0, 7.0, 8.0];
+
+        let output = get_mann_whitney_test(&group_1, &group_2, "≠")?;
+        assert!(output.contains("Normal approximation"));
+        assert!((number_field(&output, "P Value") - 0.07899322552039552).abs() < 1e-9);
+
+        let output = get_kruskal_wallis_test(&[vec![1.0; 3], vec![1.0; 4]])?;
+        assert!(output.starts_with("Ensure not every value"));
+
+        // Ranking never panics, even on values the inputs refuse
+        get_mann_whitney_test(&[1.0, f64::NAN], &[2.0, 3.0], "≠")?;
+        get_friedman_test(&[vec![1.0, f64::NAN], vec![2.0, 1.0]])?;
+        Ok(())
+    }
+
+    /// 12! orderings per row are counted, never built
+    #[test]
+    fn many_treatments_use_the_chi_square_approximation() -> Result<()> {
+        let block: Vec<f64> = (1..=12).map(f64::from).collect();
+        let output = get_friedman_test(&[block.clone(), block.clone(), block])?;
+        // Blocks in full agreement give the largest Q, n (k - 1)
+        assert!(output.starts_with("Q: 33\n") && output.contains("χ2 approximation"));
+        assert!((number_field(&output, "P Value") - chi_square_sf(33.0, 11.0)).abs() < 1e-12);
+
+        assert_eq!(ordering_count(&[2.5, 1.0, 2.5, 4.0]), 12.0);
+        assert_eq!(ordering_count(&[2.0; 10]), 1.0);
+        Ok(())
+    }
+}
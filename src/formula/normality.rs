@@ -0,0 +1,332 @@
+use super::hypothesis::{chi_square_sf, normal_cdf, normal_sf};
+use super::probability::format_probability;
+use super::statistics::{mean, std_dev};
+
+use {
+    anyhow::Result,
+    statrs::distribution::{InverseCDF, Normal},
+    std::f64::consts::{FRAC_1_SQRT_2, PI},
+};
+
+/// Sample sizes Royston's approximation to the Shapiro-Wilk test holds for
+const SHAPIRO_WILK_SIZES: (usize, usize) = (3, 5000);
+
+/// Shapiro-Wilk W with Royston's (1995) coefficients and p value, which are what most
+/// software uses
+pub fn get_shapiro_wilk(data: &[f64]) -> Result<String> {
+    let n = data.len();
+    if n < SHAPIRO_WILK_SIZES.0 || n > SHAPIRO_WILK_SIZES.1 {
+        return Ok(String::from(
+            "Ensure the data list has from 3 to 5000 values",
+        ));
+    }
+    let sorted = sorted(data);
+    let x_mean = mean(&sorted);
+    let spread: f64 = sorted.iter().map(|x| (x - x_mean).powi(2)).sum();
+    if spread == 0.0 {
+        return Ok(String::from("Ensure the values aren't all the same"));
+    }
+
+    let coefficients = shapiro_wilk_coefficients(n);
+    // The coefficients are antisymmetric, so pairing each value with its opposite gives W
+    let numerator: f64 = (0..n / 2)
+        .map(|i| coefficients[i] * (sorted[n - 1 - i] - sorted[i]))
+        .sum();
+    let w = (numerator.powi(2) / spread).min(1.0);
+
+    let p_value = if n == 3 {
+        (6.0 / PI * (w.sqrt().asin() - 0.75_f64.sqrt().asin())).max(0.0)
+    } else if n <= 11 {
+        let n = n as f64;
+        let gamma = -2.273 + 0.459 * n;
+        let mu = 0.5440 - 0.39978 * n + 0.025054 * n.powi(2) - 0.0006714 * n.powi(3);
+        let sigma = (1.3822 - 0.77857 * n + 0.062767 * n.powi(2) - 0.0020322 * n.powi(3)).exp();
+        normal_sf((-(gamma - (-w).ln_1p()).ln() - mu) / sigma)
+    } else {
+        let ln_n = (n as f64).ln();
+        let mu = -1.5861 - 0.31082 * ln_n - 0.083751 * ln_n.powi(2) + 0.0038915 * ln_n.powi(3);
+        let sigma = (-0.4803 - 0.082676 * ln_n + 0.0030302 * ln_n.powi(2)).exp();
+        normal_sf(((-w).ln_1p() - mu) / sigma)
+    };
+
+    Ok(format!(
+        "W: {}\nP Value: {}\nn: {}",
+        w,
+        format_probability(p_value),
+        n
+    ))
+}
+
+/// Weights a₁ ≥ a₂ ≥ ... of the smallest values, the largest get the same ones negated
+fn shapiro_wilk_coefficients(n: usize) -> Vec<f64> {
+    if n == 3 {
+        return vec![FRAC_1_SQRT_2];
+    }
+
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let size = n as f64;
+    let m: Vec<f64> = (1..=n / 2)
+        .map(|i| -normal.inverse_cdf((i as f64 - 0.375) / (size + 0.25)))
+        .collect();
+    let sum_squares = 2.0 * m.iter().map(|x| x.powi(2)).sum::<f64>();
+    let u = 1.0 / size.sqrt();
+    let polynomial = |coefficients: [f64; 5]| {
+        coefficients
+            .iter()
+            .enumerate()
+            .map(|(power, c)| c * u.powi(power as i32 + 1))
+            .sum::<f64>()
+    };
+
+    let a_n = m[0] / sum_squares.sqrt()
+        + polynomial([0.221157, -0.147981, -2.071190, 4.434685, -2.706056]);
+    let mut coefficients = vec![a_n];
+
+    // Royston corrects the two outermost weights above 5 values, only the last below
+    let (corrected, phi) = if n > 5 {
+        let a_n_1 = m[1] / sum_squares.sqrt()
+            + polynomial([0.042981, -0.293762, -1.752461, 5.682633, -3.582633]);
+        coefficients.push(a_n_1);
+        let phi = (sum_squares - 2.0 * m[0].powi(2) - 2.0 * m[1].powi(2))
+            / (1.0 - 2.0 * a_n.powi(2) - 2.0 * a_n_1.powi(2));
+        (2, phi)
+    } else {
+        let phi = (sum_squares - 2.0 * m[0].powi(2)) / (1.0 - 2.0 * a_n.powi(2));
+        (1, phi)
+    };
+
+    coefficients.extend(m[corrected..].iter().map(|x| x / phi.sqrt()));
+    coefficients
+}
+
+/// Anderson-Darling A² against a normal fitted to the data, with the p value of D'Agostino
+/// and Stephens (1986)
+pub fn get_anderson_darling(data: &[f64]) -> Result<String> {
+    let z = match standardized(data, 8) {
+        Ok(z) => z,
+        Err(message) => return Ok(message),
+    };
+    let n = z.len() as f64;
+
+    let sum: f64 = z
+        .iter()
+        .zip(z.iter().rev())
+        .enumerate()
+        .map(|(i, (low, high))| {
+            (2.0 * i as f64 + 1.0) * (normal_cdf(*low).ln() + normal_sf(*high).ln())
+        })
+        .sum();
+    let a_squared = -n - sum / n;
+    // Adjusted for µ and σ being estimated
+    let a = a_squared * (1.0 + 0.75 / n + 2.25 / n.powi(2));
+
+    let p_value = if a >= 0.6 {
+        (1.2937 - 5.709 * a + 0.0186 * a.powi(2)).exp()
+    } else if a >= 0.34 {
+        (0.9177 - 4.279 * a - 1.38 * a.powi(2)).exp()
+    } else if a >= 0.2 {
+        1.0 - (-8.318 + 42.796 * a - 59.938 * a.powi(2)).exp()
+    } else {
+        1.0 - (-13.436 + 101.14 * a - 223.73 * a.powi(2)).exp()
+    };
+
+    Ok(format!(
+        "A²: {}\nP Value: {}\nn: {}",
+        a_squared,
+        format_probability(p_value.clamp(0.0, 1.0)),
+        n
+    ))
+}
+
+/// Kolmogorov-Smirnov D, the largest gap between the data's cdf and a normal one. With µ and σ
+/// fitted from the data this is the Lilliefors test.
+pub fn get_kolmogorov_smirnov(data: &[f64], normal: Option<(f64, f64)>) -> Result<String> {
+    let z = match normal {
+        Some((_, std_dev)) if std_dev <= 0.0 => {
+            return Ok(String::from("Ensure σ is greater than 0"))
+        }
+        Some((mean, std_dev)) => {
+            if data.is_empty() {
+                return Ok(String::from("Ensure the data list has at least 1 value"));
+            }
+            sorted(data).iter().map(|x| (x - mean) / std_dev).collect()
+        }
+        None => match standardized(data, 5) {
+            Ok(z) => z,
+            Err(message) => return Ok(message),
+        },
+    };
+    let n = z.len() as f64;
+
+    let d = z
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let cdf = normal_cdf(x);
+            ((i + 1) as f64 / n - cdf).max(cdf - i as f64 / n)
+        })
+        .fold(0.0, f64::max);
+
+    let (p_value, method) = match normal {
+        Some((mean, std_dev)) => (
+            kolmogorov_sf((n.sqrt() + 0.12 + 0.11 / n.sqrt()) * d),
+            format!("Against N({}, {})", mean, std_dev),
+        ),
+        None => (
+            lilliefors_p_value(d, n),
+            String::from("Lilliefors, µ and σ fitted from the data"),
+        ),
+    };
+
+    Ok(format!(
+        "D: {}\nP Value: {}\nn: {}\nMethod: {}",
+        d,
+        format_probability(p_value),
+        n,
+        method
+    ))
+}
+
+/// P(K > λ) for the limiting Kolmogorov distribution
+fn kolmogorov_sf(lambda: f64) -> f64 {
+    if lambda < 0.2 {
+        return 1.0;
+    }
+    let sum: f64 = (1..=100)
+        .map(|k| {
+            let sign = if k % 2 == 1 { 1.0 } else { -1.0 };
+            sign * (-2.0 * (k as f64 * lambda).powi(2)).exp()
+        })
+        .sum();
+    (2.0 * sum).clamp(0.0, 1.0)
+}
+
+/// Dallal and Wilkinson's (1986) approximation, switching to Stephens' for large p values
+fn lilliefors_p_value(d: f64, n: f64) -> f64 {
+    let (d_n, n_d) = if n > 100.0 {
+        (d * (n / 100.0).powf(0.49), 100.0)
+    } else {
+        (d, n)
+    };
+    let p_value = (-7.01256 * d_n.powi(2) * (n_d + 2.78019)
+        + 2.99587 * d_n * (n_d + 2.78019).sqrt()
+        - 0.122119
+        + 0.974598 / n_d.sqrt()
+        + 1.67997 / n_d)
+        .exp();
+    if p_value <= 0.1 {
+        return p_value;
+    }
+
+    let k = (n.sqrt() - 0.01 + 0.85 / n.sqrt()) * d;
+    let p_value = if k <= 0.302 {
+        1.0
+    } else if k <= 0.5 {
+        2.76773 - 19.828315 * k + 80.709644 * k.powi(2) - 138.55152 * k.powi(3)
+            + 81.218052 * k.powi(4)
+    } else if k <= 0.9 {
+        -4.901232 + 40.662806 * k - 97.490286 * k.powi(2) + 94.029866 * k.powi(3)
+            - 32.355711 * k.powi(4)
+    } else if k <= 1.31 {
+        6.198765 - 19.558097 * k + 23.186922 * k.powi(2) - 12.234627 * k.powi(3)
+            + 2.423045 * k.powi(4)
+    } else {
+        0.0
+    };
+    p_value.clamp(0.0, 1.0)
+}
+
+/// Jarque-Bera JB from the sample skewness and kurtosis, against its χ2 limit with 2 df
+pub fn get_jarque_bera(data: &[f64]) -> Result<String> {
+    if data.len() < 2 {
+        return Ok(String::from("Ensure the data list has at least 2 values"));
+    }
+    let n = data.len() as f64;
+    let x_mean = mean(data);
+    let moment = |power: i32| data.iter().map(|x| (x - x_mean).powi(power)).sum::<f64>() / n;
+    if moment(2) == 0.0 {
+        return Ok(String::from("Ensure the values aren't all the same"));
+    }
+
+    let skewness = moment(3) / moment(2).powf(1.5);
+    let kurtosis = moment(4) / moment(2).powi(2);
+    let jb = n / 6.0 * (skewness.powi(2) + (kurtosis - 3.0).powi(2) / 4.0);
+
+    Ok(format!(
+        "JB: {}\nP Value: {}\nSkewness: {}\nKurtosis: {}",
+        jb,
+        format_probability(chi_square_sf(jb, 2.0)),
+        skewness,
+        kurtosis
+    ))
+}
+
+/// The sorted data as z scores with the sample mean and standard deviation
+fn standardized(data: &[f64], min_size: usize) -> Result<Vec<f64>, String> {
+    if data.len() < min_size {
+        return Err(format!(
+            "Ensure the data list has at least {} values",
+            min_size
+        ));
+    }
+    let (x_mean, s) = (mean(data), std_dev(data));
+    if s == 0.0 {
+        return Err(String::from("Ensure the values aren't all the same"));
+    }
+    Ok(sorted(data).iter().map(|x| (x - x_mean) / s).collect())
+}
+
+fn sorted(data: &[f64]) -> Vec<f64> {
+    let mut sorted = data.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variables::{number_field, result_fields, Value};
+
+    /// Shapiro and Wilk's weights of 11 men, as in R's `shapiro.test` and nortest's `ad.test` and
+    /// `lillie.test`
+    #[test]
+    fn normality_tests_match_the_weights_example() -> Result<()> {
+        let weights = [
+            148.0, 154.0, 158.0, 160.0, 161.0, 162.0, 166.0, 170.0, 182.0, 195.0, 236.0,
+        ];
+        // Each test puts its statistic first
+        let statistic = |output: &str| match result_fields(output).into_iter().next() {
+            Some((_, Value::Number(x))) => x,
+            _ => panic!("no statistic in {}", output),
+        };
+
+        let output = get_shapiro_wilk(&weights)?;
+        assert!((statistic(&output) - 0.78881).abs() < 1e-5);
+        assert!((number_field(&output, "P Value") - 0.006704).abs() < 1e-6);
+
+        let output = get_anderson_darling(&weights)?;
+        assert!((statistic(&output) - 0.94677).abs() < 1e-5);
+        assert!((number_field(&output, "P Value") - 0.010454).abs() < 1e-6);
+
+        let output = get_kolmogorov_smirnov(&weights, None)?;
+        assert!((statistic(&output) - 0.25922).abs() < 1e-5);
+        assert!((number_field(&output, "P Value") - 0.037408).abs() < 1e-6);
+        let output = get_kolmogorov_smirnov(&weights, Some((170.0, 25.0)))?;
+        assert!((statistic(&output) - 2.5 / 11.0).abs() < 1e-9);
+
+        let output = get_jarque_bera(&weights)?;
+        assert!(
+            (number_field(&output, "P Value") - (-statistic(&output) / 2.0).exp()).abs() < 1e-12
+        );
+
+        // Three values have an exact p value, the others need more
+        let output = get_shapiro_wilk(&[1.9, 2.1, 3.4])?;
+        assert!((statistic(&output) - 1.125 / 1.3266666666666667).abs() < 1e-9);
+        assert!(get_anderson_darling(&weights[..5])?.starts_with("Ensure"));
+        assert!(get_kolmogorov_smirnov(&weights, Some((170.0, 0.0)))?.starts_with("Ensure"));
+
+        // Sorting never panics, even on values the inputs refuse
+        get_shapiro_wilk(&[1.9, f64::NAN, 3.4, 2.2])?;
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use super::hypothesis::{Alternative, ALTERNATIVE_MESSAGE};
+use super::probability::{format_probability, inverse_cdf};
+use super::statistics::{mean, std_dev};
+
+use {
+    anyhow::Result,
+    statrs::distribution::{StudentsT, Univariate},
+};
+
+/// Mean difference, its standard error and degrees of freedom of after - before, or why they
+/// can't be found
+fn differences(before: &[f64], after: &[f64]) -> Result<(f64, f64, f64), String> {
+    if before.len() != after.len() || before.len() < 2 {
+        return Err(String::from(
+            "Ensure both lists have the same length, with at least 2 pairs",
+        ));
+    }
+
+    let differences: Vec<f64> = after.iter().zip(before).map(|(a, b)| a - b).collect();
+    let n = differences.len() as f64;
+    let std_error = std_dev(&differences) / n.sqrt();
+    if std_error == 0.0 {
+        return Err(String::from(
+            "Ensure the differences aren't all the same, their spread can't be 0",
+        ));
+    }
+
+    Ok((mean(&differences), std_error, n - 1.0))
+}
+
+/// Tests the mean of after - before against µ0 with a t test on the differences
+pub fn get_paired_t_test(
+    before: &[f64],
+    after: &[f64],
+    mean_0: f64,
+    alternative: &str,
+) -> Result<String> {
+    let alternative = match Alternative::parse(alternative) {
+        Some(alternative) => alternative,
+        None => return Ok(String::from(ALTERNATIVE_MESSAGE)),
+    };
+    let (difference, std_error, df) = match differences(before, after) {
+        Ok(differences) => differences,
+        Err(message) => return Ok(message),
+    };
+
+    let t = (difference - mean_0) / std_error;
+    let students_t = StudentsT::new(0.0, 1.0, df)?;
+    // The upper tail by symmetry, 1 - cdf(t) would lose it to rounding
+    let p_value = alternative.p_value(students_t.cdf(t), students_t.cdf(-t));
+
+    Ok(format!(
+        "t: {}\nP Value: {}\nMean Difference: {}\nSE: {}\ndf: {}",
+        t,
+        format_probability(p_value),
+        difference,
+        std_error,
+        df
+    ))
+}
+
+/// Confidence interval for the mean of after - before
+pub fn get_paired_t_interval(before: &[f64], after: &[f64], c_level: f64) -> Result<String> {
+    if c_level <= 0.0 || c_level >= 1.0 {
+        return Ok(String::from("Ensure the C Level is between 0 and 100"));
+    }
+    let (difference, std_error, df) = match differences(before, after) {
+        Ok(differences) => differences,
+        Err(message) => return Ok(message),
+    };
+
+    let students_t = StudentsT::new(0.0, 1.0, df)?;
+    let t_star = inverse_cdf(|x| students_t.cdf(x), (1.0 + c_level) / 2.0);
+    let margin = t_star * std_error;
+
+    Ok(format!(
+        "Lower Bound: {}\nUpper Bound: {}\nMean Difference: {}\nSE: {}\ndf: {}\nt*: {}",
+        difference - margin,
+        difference + margin,
+        difference,
+        std_error,
+        df,
+        t_star
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variables::number_field;
+
+    /// Student's sleep data, as in R's `t.test(extra ~ group, data = sleep, paired = TRUE)`
+    #[test]
+    fn paired_t_matches_the_sleep_data() -> Result<()> {
+        let group_1 = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+        let group_2 = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+        let output = get_paired_t_test(&group_2, &group_1, 0.0, "≠")?;
+        assert!((number_field(&output, "t") + 4.062128).abs() < 1e-6);
+        assert!((number_field(&output, "P Value") - 0.002832890).abs() < 1e-8);
+        assert!((number_field(&output, "Mean Difference") + 1.58).abs() < 1e-12);
+        assert_eq!(number_field(&output, "df"), 9.0);
+
+        let output = get_paired_t_interval(&group_2, &group_1, 0.95)?;
+        assert!((number_field(&output, "Lower Bound") + 2.4598858).abs() < 1e-6);
+        assert!((number_field(&output, "Upper Bound") + 0.7001142).abs() < 1e-6);
+
+        let output = get_paired_t_test(&group_1, &group_2[..9], 0.0, "≠")?;
+        assert!(output.starts_with("Ensure both lists have the same length"));
+        Ok(())
+    }
+}
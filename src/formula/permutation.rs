@@ -0,0 +1,273 @@
+use super::hypothesis::{Alternative, ALTERNATIVE_MESSAGE};
+use super::probability::format_probability;
+use super::statistics::{mean, median};
+
+use {
+    anyhow::Result,
+    rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng},
+};
+
+const MAX_PERMUTATIONS: u64 = 100_000;
+
+const BINS: usize = 12;
+
+/// Widest bar of the null distribution histogram, in characters
+const BAR_WIDTH: usize = 30;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Compare {
+    Means,
+    Medians,
+    /// Means of lists of 0s and 1s
+    Proportions,
+}
+
+/// Tests whether two groups differ by shuffling their values between them. All arrangements
+/// are tried when there are at most R of them, otherwise R random ones.
+pub fn get_permutation_test(
+    compare: &str,
+    group_1: &[f64],
+    group_2: &[f64],
+    alternative: &str,
+    permutations: u64,
+    seed: Option<u64>,
+) -> Result<String> {
+    let compare = match compare.trim().to_lowercase().as_str() {
+        "means" | "mean" => Compare::Means,
+        "medians" | "median" => Compare::Medians,
+        "proportions" | "proportion" => Compare::Proportions,
+        _ => {
+            return Ok(String::from(
+                "Ensure what is compared is Means, Medians or Proportions",
+            ))
+        }
+    };
+    let alternative = match Alternative::parse(alternative) {
+        Some(alternative) => alternative,
+        None => return Ok(String::from(ALTERNATIVE_MESSAGE)),
+    };
+
+    if group_1.is_empty() || group_2.is_empty() {
+        return Ok(String::from("Ensure both groups have at least one value"));
+    }
+    if compare == Compare::Proportions
+        && group_1.iter().chain(group_2).any(|&x| x != 0.0 && x != 1.0)
+    {
+        return Ok(String::from(
+            "Ensure the groups only hold 1 for a success and 0 for a failure",
+        ));
+    }
+    if !(100..=MAX_PERMUTATIONS).contains(&permutations) {
+        return Ok(String::from(
+            "Ensure R is a whole number from 100 to 100000",
+        ));
+    }
+
+    let pooled: Vec<f64> = group_1.iter().chain(group_2).copied().collect();
+    let size = group_1.len();
+    let difference = |group: &[f64], rest: &[f64]| match compare {
+        Compare::Medians => median(group) - median(rest),
+        _ => mean(group) - mean(rest),
+    };
+    let observed = difference(group_1, group_2);
+
+    let exact = arrangements(pooled.len(), size) <= permutations as f64;
+    let (null, method) = if exact {
+        let null = every_split(pooled.len(), size)
+            .into_iter()
+            .map(|chosen| {
+                let (group, rest) = split(&pooled, &chosen);
+                difference(&group, &rest)
+            })
+            .collect::<Vec<f64>>();
+        let method = format!("Exact, all {} arrangements", null.len());
+        (null, method)
+    } else {
+        let seed = seed.unwrap_or_else(|| rand::thread_rng().gen_range(0..1_000_000));
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut shuffled = pooled.clone();
+        let null = (0..permutations)
+            .map(|_| {
+                shuffled.shuffle(&mut rng);
+                let (group, rest) = shuffled.split_at(size);
+                difference(group, rest)
+            })
+            .collect::<Vec<f64>>();
+        let method = format!(
+            "Monte Carlo, {} random arrangements\nSeed: {}",
+            null.len(),
+            seed
+        );
+        (null, method)
+    };
+
+    let extreme = null
+        .iter()
+        .filter(|&&x| alternative.is_extreme(x, observed))
+        .count() as f64;
+    // Random arrangements count the observed one too, so p is never 0
+    let p_value = if exact {
+        extreme / null.len() as f64
+    } else {
+        (extreme + 1.0) / (null.len() as f64 + 1.0)
+    };
+
+    Ok(format!(
+        "Observed Difference: {}\nP Value: {}\nMethod: {}\n\nNull Distribution\n{}",
+        observed,
+        format_probability(p_value),
+        method,
+        histogram(&null, observed)
+    ))
+}
+
+/// How many ways `size` of `total` values can be picked for the first group
+fn arrangements(total: usize, size: usize) -> f64 {
+    (0..size).fold(1.0, |count, i| count * (total - i) as f64 / (i + 1) as f64)
+}
+
+/// Every set of `size` indices out of `total`, in increasing order
+fn every_split(total: usize, size: usize) -> Vec<Vec<usize>> {
+    let mut splits = Vec::new();
+    let mut chosen: Vec<usize> = (0..size).collect();
+
+    loop {
+        splits.push(chosen.clone());
+
+        // Move the last index that still can one step right, and pack the rest behind it
+        match (0..size).rev().find(|&i| chosen[i] < total - size + i) {
+            Some(i) => {
+                chosen[i] += 1;
+                for j in i + 1..size {
+                    chosen[j] = chosen[j - 1] + 1;
+                }
+            }
+            None => return splits,
+        }
+    }
+}
+
+fn split(pooled: &[f64], chosen: &[usize]) -> (Vec<f64>, Vec<f64>) {
+    let mut group = Vec::with_capacity(chosen.len());
+    let mut rest = Vec::with_capacity(pooled.len() - chosen.len());
+    let mut next = chosen.iter().peekable();
+
+    for (i, &value) in pooled.iter().enumerate() {
+        if next.peek() == Some(&&i) {
+            next.next();
+            group.push(value);
+        } else {
+            rest.push(value);
+        }
+    }
+    (group, rest)
+}
+
+/// One line per bin with a bar of block characters, the observed difference's bin marked by ◀
+fn histogram(null: &[f64], observed: f64) -> String {
+    let min = null.iter().copied().fold(observed, f64::min);
+    let max = null.iter().copied().fold(observed, f64::max);
+    let width = if max > min {
+        (max - min) / BINS as f64
+    } else {
+        1.0
+    };
+    let bin = |x: f64| (((x - min) / width) as usize).min(BINS - 1);
+
+    let mut counts = [0_usize; BINS];
+    for &x in null {
+        counts[bin(x)] += 1;
+    }
+    let most = *counts.iter().max().unwrap();
+
+    // Every line is padded to the same width, so they still line up in the centered output
+    let ranges: Vec<String> = (0..BINS)
+        .map(|i| {
+            format!(
+                "{:.3} to {:.3}",
+                min + i as f64 * width,
+                min + (i + 1) as f64 * width
+            )
+        })
+        .collect();
+    let range_width = ranges
+        .iter()
+        .map(|range| range.chars().count())
+        .max()
+        .unwrap();
+    let count_width = most.to_string().len();
+
+    counts
+        .iter()
+        .zip(&ranges)
+        .enumerate()
+        .map(|(i, (&count, range))| {
+            format!(
+                "{:<range_width$} {:<bar_width$} {:<count_width$} {}",
+                range,
+                "█".repeat((count * BAR_WIDTH).div_ceil(most)),
+                count,
+                if i == bin(observed) { "◀" } else { " " },
+                range_width = range_width,
+                bar_width = BAR_WIDTH,
+                count_width = count_width
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variables::number_field;
+
+    #[test]
+    fn exact_permutation_tests_count_every_arrangement() -> Result<()> {
+        let (low, high) = ([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+
+        let output = get_permutation_test("Means", &low, &high, "<", 100, None)?;
+        assert!(output.contains("Exact, all 20 arrangements"));
+        assert!((number_field(&output, "P Value") - 0.05).abs() < 1e-12);
+        assert!(
+            (number_field(
+                &get_permutation_test("Means", &low, &high, "≠", 100, None)?,
+                "P Value"
+            ) - 0.1)
+                .abs()
+                < 1e-12
+        );
+        assert!(
+            (number_field(
+                &get_permutation_test("Medians", &low, &high, ">", 100, None)?,
+                "P Value"
+            ) - 1.0)
+                .abs()
+                < 1e-12
+        );
+
+        let output = get_permutation_test("Proportions", &[1.0, 0.5], &[0.0], "≠", 100, None)?;
+        assert!(output.starts_with("Ensure the groups only hold 1"));
+
+        // Medians never panic, even on values the inputs refuse
+        get_permutation_test("Medians", &[1.0, f64::NAN], &high, "≠", 100, None)?;
+        Ok(())
+    }
+
+    #[test]
+    fn monte_carlo_permutation_tests_are_close_to_exact() -> Result<()> {
+        let group_1 = [12.0, 15.0, 9.0, 14.0, 11.0, 13.0, 16.0, 10.0];
+        let group_2 = [8.0, 11.0, 7.0, 12.0, 9.0, 10.0, 6.0, 13.0];
+
+        // 12870 arrangements in all
+        let exact = get_permutation_test("Means", &group_1, &group_2, "≠", 20000, None)?;
+        let sampled = get_permutation_test("Means", &group_1, &group_2, "≠", 5000, Some(2))?;
+        assert!(sampled.contains("Monte Carlo, 5000 random arrangements"));
+        assert!((number_field(&exact, "P Value") - number_field(&sampled, "P Value")).abs() < 0.01);
+        assert_eq!(
+            sampled,
+            get_permutation_test("Means", &group_1, &group_2, "≠", 5000, Some(2))?
+        );
+        Ok(())
+    }
+}
@@ -2,13 +2,185 @@ use {
     anyhow::Result,
     statrs::{
         distribution::{
-            Binomial, ChiSquared, Continuous, Discrete, FisherSnedecor, Geometric, InverseCDF,
-            Normal, Poisson, StudentsT, Univariate,
+            Bernoulli, Beta, Binomial, Cauchy, CheckedDiscrete, ChiSquared, Continuous, Discrete,
+            DiscreteUniform, Exponential, FisherSnedecor, Gamma, Geometric, Hypergeometric,
+            InverseCDF, LogNormal, Multinomial, NegativeBinomial, Normal, Poisson, StudentsT,
+            Uniform, Univariate, Weibull,
         },
-        function::factorial::factorial,
+        function::{beta::beta_reg, erf::erfc, factorial::factorial, gamma::gamma_ur},
     },
+    std::f64::consts::{PI, SQRT_2},
 };
 
+/// Which values a Cdf measures the probability of
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Range {
+    AtMost(f64),
+    LessThan(f64),
+    AtLeast(f64),
+    MoreThan(f64),
+    Between(f64, f64),
+    Outside(f64, f64),
+}
+
+impl Range {
+    /// Reads the Range selector of a discrete Cdf, ≤ and < use the upper bound, ≥ and > the
+    /// lower one
+    pub fn parse(selector: &str, lower_bound: &str, upper_bound: &str) -> Option<Range> {
+        let lower = parse_bound(lower_bound, f64::NEG_INFINITY);
+        let upper = parse_bound(upper_bound, f64::INFINITY);
+
+        match selector.trim().to_lowercase().as_str() {
+            "≤" | "<=" => Some(Range::AtMost(upper?)),
+            "<" => Some(Range::LessThan(upper?)),
+            "≥" | ">=" => Some(Range::AtLeast(lower?)),
+            ">" => Some(Range::MoreThan(lower?)),
+            "between" => Some(Range::Between(lower?, upper?)),
+            "outside" => Some(Range::Outside(lower?, upper?)),
+            _ => None,
+        }
+    }
+
+    /// Reads the bounds of a continuous Cdf. Instead of a number either bound can hold one of
+    /// the shortcuts `left of x`, `right of x`, `between a and b` or `outside a and b`.
+    pub fn parse_bounds(lower_bound: &str, upper_bound: &str) -> Option<Range> {
+        for bound in [lower_bound, upper_bound].iter() {
+            let bound = bound.trim().to_lowercase();
+
+            if let Some(x) = bound.strip_prefix("left of") {
+                return Some(Range::AtMost(parse_number(x)?));
+            } else if let Some(x) = bound.strip_prefix("right of") {
+                return Some(Range::AtLeast(parse_number(x)?));
+            } else if let Some(bounds) = bound.strip_prefix("between") {
+                let (lower, upper) = parse_two_bounds(bounds)?;
+                return Some(Range::Between(lower, upper));
+            } else if let Some(bounds) = bound.strip_prefix("outside") {
+                let (lower, upper) = parse_two_bounds(bounds)?;
+                return Some(Range::Outside(lower, upper));
+            }
+        }
+
+        Some(Range::Between(
+            parse_bound(lower_bound, f64::NEG_INFINITY)?,
+            parse_bound(upper_bound, f64::INFINITY)?,
+        ))
+    }
+
+    /// P(X in range) for a variable on whole numbers, given its cdf(x) = P(X ≤ x)
+    pub fn probability(self, cdf: impl Fn(f64) -> f64) -> f64 {
+        let cdf = |x: f64| clamp_cdf(&cdf, x);
+
+        // The largest whole number below a bound is ceil(bound) - 1, which leaves it out
+        match self {
+            Range::AtMost(x) => cdf(x.floor()),
+            Range::LessThan(x) => cdf(x.ceil() - 1.0),
+            Range::AtLeast(x) => 1.0 - cdf(x.ceil() - 1.0),
+            Range::MoreThan(x) => 1.0 - cdf(x.floor()),
+            Range::Between(lower, upper) => {
+                if lower > upper {
+                    0.0
+                } else {
+                    cdf(upper.floor()) - cdf(lower.ceil() - 1.0)
+                }
+            }
+            Range::Outside(lower, upper) => {
+                if lower > upper {
+                    1.0
+                } else {
+                    cdf(lower.ceil() - 1.0) + (1.0 - cdf(upper.floor()))
+                }
+            }
+        }
+    }
+
+    /// P(X in range) for a continuous variable, where it makes no difference whether a bound
+    /// is included. Upper tails come from the survival function sf(x) = P(X > x), as
+    /// 1 - cdf(x) rounds to 0 once the tail is smaller than about 1e-16.
+    pub fn continuous_probability(self, cdf: impl Fn(f64) -> f64, sf: impl Fn(f64) -> f64) -> f64 {
+        let cdf = |x: f64| clamp_cdf(&cdf, x);
+        let sf = |x: f64| clamp_sf(&sf, x);
+
+        match self {
+            Range::AtMost(x) | Range::LessThan(x) => cdf(x),
+            Range::AtLeast(x) | Range::MoreThan(x) => sf(x),
+            Range::Between(lower, upper) => {
+                if lower > upper {
+                    0.0
+                } else if cdf(lower) > 0.5 {
+                    // Both bounds are in the upper tail, where the sf is the precise one
+                    sf(lower) - sf(upper)
+                } else {
+                    cdf(upper) - cdf(lower)
+                }
+            }
+            Range::Outside(lower, upper) => {
+                if lower > upper {
+                    1.0
+                } else {
+                    cdf(lower) + sf(upper)
+                }
+            }
+        }
+    }
+}
+
+/// Reads a bound, which may also be `inf`, `-inf`, `∞` or `-∞`. A blank bound is `blank`.
+fn parse_bound(bound: &str, blank: f64) -> Option<f64> {
+    if bound.trim().is_empty() {
+        Some(blank)
+    } else {
+        parse_number(bound)
+    }
+}
+
+fn parse_number(number: &str) -> Option<f64> {
+    number.trim().replace('∞', "inf").parse::<f64>().ok()
+}
+
+/// Reads the two bounds after `between` or `outside`, as `a and b` or `a, b`
+fn parse_two_bounds(bounds: &str) -> Option<(f64, f64)> {
+    let bounds = bounds.replace(" and ", ",");
+    let mut bounds = bounds.split(',');
+
+    let lower = parse_number(bounds.next()?)?;
+    let upper = parse_number(bounds.next()?)?;
+
+    if bounds.next().is_some() {
+        return None;
+    }
+    Some((lower, upper))
+}
+
+/// statrs doesn't reach exactly 0 and 1 at ±∞ for every distribution
+fn clamp_cdf(cdf: impl Fn(f64) -> f64, x: f64) -> f64 {
+    if x == f64::NEG_INFINITY {
+        0.0
+    } else if x == f64::INFINITY {
+        1.0
+    } else {
+        cdf(x)
+    }
+}
+
+fn clamp_sf(sf: impl Fn(f64) -> f64, x: f64) -> f64 {
+    if x == f64::NEG_INFINITY {
+        1.0
+    } else if x == f64::INFINITY {
+        0.0
+    } else {
+        sf(x)
+    }
+}
+
+/// Writes tiny probabilities in scientific notation rather than as a long run of zeros
+pub fn format_probability(probability: f64) -> String {
+    if probability != 0.0 && probability.abs() < 1e-4 {
+        format!("{:e}", probability)
+    } else {
+        format!("{}", probability)
+    }
+}
+
 pub fn get_factorial(n: u64) -> Result<String> {
     Ok(format!("{}", factorial(n)))
 }
@@ -36,12 +208,7 @@ pub fn get_normal_pdf(x: f64, mean: f64, std_dev: f64) -> Result<String> {
     Ok(format!("{}", normal?.pdf(x)))
 }
 
-pub fn get_normal_cdf(
-    lower_bound: f64,
-    upper_bound: f64,
-    mean: f64,
-    std_dev: f64,
-) -> Result<String> {
+pub fn get_normal_cdf(range: Range, mean: f64, std_dev: f64) -> Result<String> {
     let mut normal = Normal::new(mean, std_dev);
 
     if normal.is_err() {
@@ -51,10 +218,10 @@ pub fn get_normal_cdf(
     };
 
     let normal = normal?;
+    let sf = |x: f64| 0.5 * erfc((x - mean) / (std_dev * SQRT_2));
 
-    Ok(format!(
-        "{}",
-        normal.cdf(upper_bound) - normal.cdf(lower_bound)
+    Ok(format_probability(
+        range.continuous_probability(|x| normal.cdf(x), sf),
     ))
 }
 
@@ -82,18 +249,39 @@ pub fn get_t_pdf(x: f64, df: f64) -> Result<String> {
     Ok(format!("{}", t?.pdf(x)))
 }
 
-pub fn get_t_cdf(lower_bound: f64, upper_bound: f64, df: f64) -> Result<String> {
+pub fn get_t_cdf(range: Range, df: f64) -> Result<String> {
     let mut t = StudentsT::new(0.0, 1.0, df);
 
     if t.is_err() {
         return Ok(String::from(
-            "Ensure both bounds and df are numbers, and that df is greater than 0",
+            "Ensure df is a number, and that df is greater than 0",
         ));
     };
 
     let t = t?;
 
-    Ok(format!("{}", t.cdf(upper_bound) - t.cdf(lower_bound)))
+    // t is symmetric about 0, so its upper tail is the lower tail reflected
+    Ok(format_probability(
+        range.continuous_probability(|x| t.cdf(x), |x| t.cdf(-x)),
+    ))
+}
+
+pub fn get_inv_t(area: f64, df: f64) -> Result<String> {
+    let t = StudentsT::new(0.0, 1.0, df);
+
+    if t.is_err() {
+        return Ok(String::from(
+            "Ensure area and df are numbers, and that df is greater than 0",
+        ));
+    };
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    let t = t?;
+
+    Ok(format!("{}", inverse_cdf(|x| t.cdf(x), area)))
 }
 
 pub fn get_chi_square_pdf(x: f64, df: f64) -> Result<String> {
@@ -108,22 +296,47 @@ pub fn get_chi_square_pdf(x: f64, df: f64) -> Result<String> {
     Ok(format!("{}", chi_square?.pdf(x)))
 }
 
-pub fn get_chi_square_cdf(lower_bound: f64, upper_bound: f64, df: f64) -> Result<String> {
+pub fn get_chi_square_cdf(range: Range, df: f64) -> Result<String> {
     let mut chi_square = ChiSquared::new(df);
 
     if chi_square.is_err() {
         return Ok(String::from(
-            "Ensure bounds and df are numbers, and that df is greater than 0",
+            "Ensure df is a number, and that df is greater than 0",
         ));
     };
 
     let chi_square = chi_square?;
+    let sf = |x: f64| {
+        if x > 0.0 {
+            gamma_ur(df / 2.0, x / 2.0)
+        } else {
+            1.0
+        }
+    };
 
-    Ok(format!(
-        "{}",
-        chi_square.cdf(upper_bound) - chi_square.cdf(lower_bound)
+    Ok(format_probability(
+        range.continuous_probability(|x| chi_square.cdf(x), sf),
     ))
 }
+
+pub fn get_inv_chi_square(area: f64, df: f64) -> Result<String> {
+    let chi_square = ChiSquared::new(df);
+
+    if chi_square.is_err() {
+        return Ok(String::from(
+            "Ensure area and df are numbers, and that df is greater than 0",
+        ));
+    };
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    let chi_square = chi_square?;
+
+    Ok(format!("{}", inverse_cdf(|x| chi_square.cdf(x), area)))
+}
+
 pub fn get_binom_pdf(n: u64, p: f64, x: u64) -> Result<String> {
     let mut binom = Binomial::new(p, n);
 
@@ -136,19 +349,16 @@ pub fn get_binom_pdf(n: u64, p: f64, x: u64) -> Result<String> {
     Ok(format!("{}", binom?.pmf(x)))
 }
 
-pub fn get_binom_cdf(n: u64, p: f64, lower_bound: f64, upper_bound: f64) -> Result<String> {
+pub fn get_binom_cdf(n: u64, p: f64, range: Range) -> Result<String> {
     let mut binom = Binomial::new(p, n);
 
     if binom.is_err() {
-        return Ok(String::from("Ensure bounds, n, and p are numbers"));
+        return Ok(String::from("Ensure n and p are numbers"));
     };
 
     let binom = binom?;
 
-    Ok(format!(
-        "{}",
-        binom.cdf(upper_bound) - binom.cdf(lower_bound)
-    ))
+    Ok(format_probability(range.probability(|x| binom.cdf(x))))
 }
 
 pub fn get_f_pdf(df_1: f64, df_2: f64, x: f64) -> Result<String> {
@@ -161,16 +371,25 @@ pub fn get_f_pdf(df_1: f64, df_2: f64, x: f64) -> Result<String> {
     Ok(format!("{}", f?.pdf(x)))
 }
 
-pub fn get_f_cdf(lower_bound: f64, upper_bound: f64, df_1: f64, df_2: f64) -> Result<String> {
+pub fn get_f_cdf(range: Range, df_1: f64, df_2: f64) -> Result<String> {
     let f = FisherSnedecor::new(df_1, df_2);
 
     if f.is_err() {
-        return Ok(String::from("Ensure df 1, df 2, and bounds are numbers"));
+        return Ok(String::from("Ensure df 1 and df 2 are numbers"));
     }
 
     let f = f?;
+    let sf = |x: f64| {
+        if x > 0.0 {
+            beta_reg(df_2 / 2.0, df_1 / 2.0, df_2 / (df_2 + df_1 * x))
+        } else {
+            1.0
+        }
+    };
 
-    Ok(format!("{}", f.cdf(upper_bound) - f.cdf(lower_bound)))
+    Ok(format_probability(
+        range.continuous_probability(|x| f.cdf(x), sf),
+    ))
 }
 
 pub fn get_geo_pdf(p: f64, x: u64) -> Result<String> {
@@ -183,19 +402,16 @@ pub fn get_geo_pdf(p: f64, x: u64) -> Result<String> {
     Ok(format!("{}", geo?.pmf(x)))
 }
 
-pub fn get_geo_cdf(p: f64, lower_bound: f64, upper_bound: f64) -> Result<String> {
+pub fn get_geo_cdf(p: f64, range: Range) -> Result<String> {
     let geo = Geometric::new(p);
 
     if geo.is_err() {
-        return Ok(String::from("Ensure p and bounds are numbers"));
+        return Ok(String::from("Ensure p is a number"));
     }
 
     let geo = geo?;
 
-    Ok(format!(
-        "{}",
-        geo.cdf(upper_bound) - geo.cdf(lower_bound) + p
-    ))
+    Ok(format_probability(range.probability(|x| geo.cdf(x))))
 }
 
 pub fn get_poisson_pdf(lambda: f64, x: u64) -> Result<String> {
@@ -208,17 +424,983 @@ pub fn get_poisson_pdf(lambda: f64, x: u64) -> Result<String> {
     Ok(format!("{}", poisson?.pmf(x)))
 }
 
-pub fn get_poisson_cdf(lambda: f64, lower_bound: f64, upper_bound: f64) -> Result<String> {
+pub fn get_poisson_cdf(lambda: f64, range: Range) -> Result<String> {
     let poisson = Poisson::new(lambda);
 
     if poisson.is_err() {
-        return Ok(String::from("Ensure lambda and bounds are numbers"));
+        return Ok(String::from("Ensure lambda is a number"));
     }
 
     let poisson = poisson?;
 
+    Ok(format_probability(range.probability(|x| poisson.cdf(x))))
+}
+
+pub fn get_exp_pdf(x: f64, rate: f64) -> Result<String> {
+    let exp = Exponential::new(rate);
+
+    if exp.is_err() {
+        return Ok(String::from(
+            "Ensure x and λ are numbers, and that λ is greater than 0",
+        ));
+    }
+
+    Ok(format!("{}", exp?.pdf(x)))
+}
+
+pub fn get_exp_cdf(range: Range, rate: f64) -> Result<String> {
+    let exp = Exponential::new(rate);
+
+    if exp.is_err() {
+        return Ok(String::from("Ensure λ is a number greater than 0"));
+    }
+
+    let exp = exp?;
+    let sf = |x: f64| {
+        if x > 0.0 {
+            (-rate * x).exp()
+        } else {
+            1.0
+        }
+    };
+
+    Ok(format_probability(
+        range.continuous_probability(|x| exp.cdf(x), sf),
+    ))
+}
+
+pub fn get_inv_exp(area: f64, rate: f64) -> Result<String> {
+    if Exponential::new(rate).is_err() {
+        return Ok(String::from(
+            "Ensure area and λ are numbers, and that λ is greater than 0",
+        ));
+    }
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    Ok(format!("{}", -(1.0 - area).ln() / rate))
+}
+
+pub fn get_uniform_pdf(x: f64, min: f64, max: f64) -> Result<String> {
+    let uniform = Uniform::new(min, max);
+
+    if uniform.is_err() || min == max {
+        return Ok(String::from(
+            "Ensure x, a and b are numbers, and that a is less than b",
+        ));
+    }
+
+    Ok(format!("{}", uniform?.pdf(x)))
+}
+
+pub fn get_uniform_cdf(range: Range, min: f64, max: f64) -> Result<String> {
+    let uniform = Uniform::new(min, max);
+
+    if uniform.is_err() || min == max {
+        return Ok(String::from(
+            "Ensure a and b are numbers, and that a is less than b",
+        ));
+    }
+
+    let uniform = uniform?;
+    let sf = |x: f64| ((max - x) / (max - min)).clamp(0.0, 1.0);
+
+    Ok(format_probability(
+        range.continuous_probability(|x| uniform.cdf(x), sf),
+    ))
+}
+
+pub fn get_inv_uniform(area: f64, min: f64, max: f64) -> Result<String> {
+    if Uniform::new(min, max).is_err() || min == max {
+        return Ok(String::from(
+            "Ensure area, a and b are numbers, and that a is less than b",
+        ));
+    }
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    Ok(format!("{}", min + area * (max - min)))
+}
+
+pub fn get_gamma_pdf(x: f64, shape: f64, rate: f64) -> Result<String> {
+    let gamma = Gamma::new(shape, rate);
+
+    if gamma.is_err() {
+        return Ok(String::from(
+            "Ensure x, α and β are numbers, and that α and β are greater than 0",
+        ));
+    }
+
+    Ok(format!("{}", gamma?.pdf(x)))
+}
+
+pub fn get_gamma_cdf(range: Range, shape: f64, rate: f64) -> Result<String> {
+    let gamma = Gamma::new(shape, rate);
+
+    if gamma.is_err() {
+        return Ok(String::from(
+            "Ensure α and β are numbers, and that α and β are greater than 0",
+        ));
+    }
+
+    let gamma = gamma?;
+    let sf = |x: f64| {
+        if x > 0.0 {
+            gamma_ur(shape, rate * x)
+        } else {
+            1.0
+        }
+    };
+
+    Ok(format_probability(
+        range.continuous_probability(|x| gamma.cdf(x), sf),
+    ))
+}
+
+pub fn get_inv_gamma(area: f64, shape: f64, rate: f64) -> Result<String> {
+    let gamma = Gamma::new(shape, rate);
+
+    if gamma.is_err() {
+        return Ok(String::from(
+            "Ensure area, α and β are numbers, and that α and β are greater than 0",
+        ));
+    }
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    let gamma = gamma?;
+
+    Ok(format!("{}", inverse_cdf(|x| gamma.cdf(x), area)))
+}
+
+pub fn get_beta_pdf(x: f64, shape_a: f64, shape_b: f64) -> Result<String> {
+    let beta = Beta::new(shape_a, shape_b);
+
+    if beta.is_err() {
+        return Ok(String::from(
+            "Ensure x, α and β are numbers, and that α and β are greater than 0",
+        ));
+    }
+
+    Ok(format!("{}", beta?.pdf(x)))
+}
+
+pub fn get_beta_cdf(range: Range, shape_a: f64, shape_b: f64) -> Result<String> {
+    let beta = Beta::new(shape_a, shape_b);
+
+    if beta.is_err() {
+        return Ok(String::from(
+            "Ensure α and β are numbers, and that α and β are greater than 0",
+        ));
+    }
+
+    let beta = beta?;
+    let sf = |x: f64| {
+        if x <= 0.0 {
+            1.0
+        } else if x >= 1.0 {
+            0.0
+        } else {
+            beta_reg(shape_b, shape_a, 1.0 - x)
+        }
+    };
+
+    Ok(format_probability(
+        range.continuous_probability(|x| beta.cdf(x), sf),
+    ))
+}
+
+pub fn get_inv_beta(area: f64, shape_a: f64, shape_b: f64) -> Result<String> {
+    let beta = Beta::new(shape_a, shape_b);
+
+    if beta.is_err() {
+        return Ok(String::from(
+            "Ensure area, α and β are numbers, and that α and β are greater than 0",
+        ));
+    }
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    let beta = beta?;
+
+    Ok(format!("{}", inverse_cdf(|x| beta.cdf(x), area)))
+}
+
+pub fn get_lognormal_pdf(x: f64, mean: f64, std_dev: f64) -> Result<String> {
+    let lognormal = LogNormal::new(mean, std_dev);
+
+    if lognormal.is_err() {
+        return Ok(String::from(
+            "Ensure x, µ and σ are numbers, and that σ is greater than 0",
+        ));
+    }
+
+    Ok(format!("{}", lognormal?.pdf(x)))
+}
+
+pub fn get_lognormal_cdf(range: Range, mean: f64, std_dev: f64) -> Result<String> {
+    let lognormal = LogNormal::new(mean, std_dev);
+
+    if lognormal.is_err() {
+        return Ok(String::from(
+            "Ensure µ and σ are numbers, and that σ is greater than 0",
+        ));
+    }
+
+    let lognormal = lognormal?;
+    let sf = |x: f64| {
+        if x > 0.0 {
+            0.5 * erfc((x.ln() - mean) / (std_dev * SQRT_2))
+        } else {
+            1.0
+        }
+    };
+
+    Ok(format_probability(
+        range.continuous_probability(|x| lognormal.cdf(x), sf),
+    ))
+}
+
+pub fn get_inv_lognormal(area: f64, mean: f64, std_dev: f64) -> Result<String> {
+    let normal = Normal::new(mean, std_dev);
+
+    if normal.is_err() {
+        return Ok(String::from(
+            "Ensure area, µ and σ are numbers, and that σ is greater than 0",
+        ));
+    }
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    Ok(format!("{}", normal?.inverse_cdf(area).exp()))
+}
+
+pub fn get_weibull_pdf(x: f64, shape: f64, scale: f64) -> Result<String> {
+    let weibull = Weibull::new(shape, scale);
+
+    if weibull.is_err() {
+        return Ok(String::from(
+            "Ensure x, k and λ are numbers, and that k and λ are greater than 0",
+        ));
+    }
+
+    Ok(format!("{}", weibull?.pdf(x)))
+}
+
+pub fn get_weibull_cdf(range: Range, shape: f64, scale: f64) -> Result<String> {
+    let weibull = Weibull::new(shape, scale);
+
+    if weibull.is_err() {
+        return Ok(String::from(
+            "Ensure k and λ are numbers, and that k and λ are greater than 0",
+        ));
+    }
+
+    let weibull = weibull?;
+    let sf = |x: f64| {
+        if x > 0.0 {
+            (-(x / scale).powf(shape)).exp()
+        } else {
+            1.0
+        }
+    };
+
+    Ok(format_probability(
+        range.continuous_probability(|x| weibull.cdf(x), sf),
+    ))
+}
+
+pub fn get_inv_weibull(area: f64, shape: f64, scale: f64) -> Result<String> {
+    if Weibull::new(shape, scale).is_err() {
+        return Ok(String::from(
+            "Ensure area, k and λ are numbers, and that k and λ are greater than 0",
+        ));
+    }
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    Ok(format!(
+        "{}",
+        scale * (-(1.0 - area).ln()).powf(1.0 / shape)
+    ))
+}
+
+pub fn get_cauchy_pdf(x: f64, location: f64, scale: f64) -> Result<String> {
+    let cauchy = Cauchy::new(location, scale);
+
+    if cauchy.is_err() {
+        return Ok(String::from(
+            "Ensure x, x0 and γ are numbers, and that γ is greater than 0",
+        ));
+    }
+
+    Ok(format!("{}", cauchy?.pdf(x)))
+}
+
+pub fn get_cauchy_cdf(range: Range, location: f64, scale: f64) -> Result<String> {
+    let cauchy = Cauchy::new(location, scale);
+
+    if cauchy.is_err() {
+        return Ok(String::from(
+            "Ensure x0 and γ are numbers, and that γ is greater than 0",
+        ));
+    }
+
+    let cauchy = cauchy?;
+    let sf = |x: f64| scale.atan2(x - location) / PI;
+
+    Ok(format_probability(
+        range.continuous_probability(|x| cauchy.cdf(x), sf),
+    ))
+}
+
+pub fn get_inv_cauchy(area: f64, location: f64, scale: f64) -> Result<String> {
+    if Cauchy::new(location, scale).is_err() {
+        return Ok(String::from(
+            "Ensure area, x0 and γ are numbers, and that γ is greater than 0",
+        ));
+    }
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    Ok(format!("{}", location + scale * (PI * (area - 0.5)).tan()))
+}
+
+// statrs has no logistic distribution, its functions are simple enough to write out
+
+pub fn get_logistic_pdf(x: f64, mean: f64, scale: f64) -> Result<String> {
+    if scale.is_nan() || scale <= 0.0 {
+        return Ok(String::from(
+            "Ensure x, µ and s are numbers, and that s is greater than 0",
+        ));
+    }
+
+    // Written with e^(-|z|) so large |z| doesn't overflow
+    let z = ((x - mean) / scale).abs();
+
     Ok(format!(
         "{}",
-        poisson.cdf(upper_bound) - poisson.cdf(lower_bound - 1.0)
+        (-z).exp() / (scale * (1.0 + (-z).exp()).powi(2))
     ))
 }
+
+pub fn get_logistic_cdf(range: Range, mean: f64, scale: f64) -> Result<String> {
+    if scale.is_nan() || scale <= 0.0 {
+        return Ok(String::from(
+            "Ensure µ and s are numbers, and that s is greater than 0",
+        ));
+    }
+
+    let cdf = |x: f64| 1.0 / (1.0 + (-(x - mean) / scale).exp());
+    let sf = |x: f64| 1.0 / (1.0 + ((x - mean) / scale).exp());
+
+    Ok(format_probability(range.continuous_probability(cdf, sf)))
+}
+
+pub fn get_inv_logistic(area: f64, mean: f64, scale: f64) -> Result<String> {
+    if scale.is_nan() || scale <= 0.0 {
+        return Ok(String::from(
+            "Ensure area, µ and s are numbers, and that s is greater than 0",
+        ));
+    }
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    Ok(format!("{}", mean + scale * (area / (1.0 - area)).ln()))
+}
+
+pub fn get_scaled_t_pdf(x: f64, location: f64, scale: f64, df: f64) -> Result<String> {
+    let t = StudentsT::new(location, scale, df);
+
+    if t.is_err() {
+        return Ok(String::from(
+            "Ensure x, µ, s and df are numbers, and that s and df are greater than 0",
+        ));
+    }
+
+    Ok(format!("{}", t?.pdf(x)))
+}
+
+pub fn get_scaled_t_cdf(range: Range, location: f64, scale: f64, df: f64) -> Result<String> {
+    let t = StudentsT::new(location, scale, df);
+
+    if t.is_err() {
+        return Ok(String::from(
+            "Ensure µ, s and df are numbers, and that s and df are greater than 0",
+        ));
+    }
+
+    let t = t?;
+
+    Ok(format_probability(range.continuous_probability(
+        |x| t.cdf(x),
+        |x| t.cdf(2.0 * location - x),
+    )))
+}
+
+pub fn get_inv_scaled_t(area: f64, location: f64, scale: f64, df: f64) -> Result<String> {
+    let t = StudentsT::new(0.0, 1.0, df);
+
+    if t.is_err() || scale.is_nan() || scale <= 0.0 {
+        return Ok(String::from(
+            "Ensure area, µ, s and df are numbers, and that s and df are greater than 0",
+        ));
+    }
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    let t = t?;
+
+    Ok(format!(
+        "{}",
+        location + scale * inverse_cdf(|x| t.cdf(x), area)
+    ))
+}
+
+pub fn get_hypergeo_pdf(population: u64, successes: u64, draws: u64, x: u64) -> Result<String> {
+    let hypergeo = Hypergeometric::new(population, successes, draws);
+
+    if hypergeo.is_err() {
+        return Ok(String::from(
+            "Ensure N, K, n and x are whole numbers, and that K and n are at most N",
+        ));
+    }
+
+    Ok(format!("{}", hypergeo?.pmf(x)))
+}
+
+pub fn get_hypergeo_cdf(
+    population: u64,
+    successes: u64,
+    draws: u64,
+    range: Range,
+) -> Result<String> {
+    let hypergeo = Hypergeometric::new(population, successes, draws);
+
+    if hypergeo.is_err() {
+        return Ok(String::from(
+            "Ensure N, K and n are whole numbers, and that K and n are at most N",
+        ));
+    }
+
+    let hypergeo = hypergeo?;
+
+    Ok(format_probability(range.probability(|x| hypergeo.cdf(x))))
+}
+
+pub fn get_inv_hypergeo(area: f64, population: u64, successes: u64, draws: u64) -> Result<String> {
+    let hypergeo = Hypergeometric::new(population, successes, draws);
+
+    if hypergeo.is_err() {
+        return Ok(String::from(
+            "Ensure N, K and n are whole numbers, and that K and n are at most N",
+        ));
+    }
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    let hypergeo = hypergeo?;
+
+    Ok(format!(
+        "{}",
+        discrete_inverse_cdf(|x| hypergeo.cdf(x), area, 0.0)
+    ))
+}
+
+pub fn get_neg_binom_pdf(r: f64, p: f64, x: u64) -> Result<String> {
+    let neg_binom = NegativeBinomial::new(r, p);
+
+    if neg_binom.is_err() || p == 0.0 {
+        return Ok(String::from(
+            "Ensure r, p and x are numbers, that r is at least 0 and p is between 0 and 1",
+        ));
+    }
+
+    Ok(format!("{}", neg_binom?.pmf(x)))
+}
+
+pub fn get_neg_binom_cdf(r: f64, p: f64, range: Range) -> Result<String> {
+    let neg_binom = NegativeBinomial::new(r, p);
+
+    if neg_binom.is_err() || p == 0.0 {
+        return Ok(String::from(
+            "Ensure r and p are numbers, that r is at least 0 and p is between 0 and 1",
+        ));
+    }
+
+    let neg_binom = neg_binom?;
+
+    Ok(format_probability(range.probability(|x| neg_binom.cdf(x))))
+}
+
+pub fn get_inv_neg_binom(area: f64, r: f64, p: f64) -> Result<String> {
+    let neg_binom = NegativeBinomial::new(r, p);
+
+    if neg_binom.is_err() || p == 0.0 {
+        return Ok(String::from(
+            "Ensure area, r and p are numbers, that r is at least 0 and p is between 0 and 1",
+        ));
+    }
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    let neg_binom = neg_binom?;
+
+    Ok(format!(
+        "{}",
+        discrete_inverse_cdf(|x| neg_binom.cdf(x), area, 0.0)
+    ))
+}
+
+pub fn get_discrete_uniform_pdf(min: i64, max: i64, x: i64) -> Result<String> {
+    let uniform = DiscreteUniform::new(min, max);
+
+    if uniform.is_err() {
+        return Ok(String::from(
+            "Ensure a, b and x are whole numbers, and that a is at most b",
+        ));
+    }
+
+    Ok(format!("{}", uniform?.pmf(x)))
+}
+
+pub fn get_discrete_uniform_cdf(min: i64, max: i64, range: Range) -> Result<String> {
+    let uniform = DiscreteUniform::new(min, max);
+
+    if uniform.is_err() {
+        return Ok(String::from(
+            "Ensure a and b are whole numbers, and that a is at most b",
+        ));
+    }
+
+    let uniform = uniform?;
+
+    Ok(format_probability(range.probability(|x| uniform.cdf(x))))
+}
+
+pub fn get_inv_discrete_uniform(area: f64, min: i64, max: i64) -> Result<String> {
+    let uniform = DiscreteUniform::new(min, max);
+
+    if uniform.is_err() {
+        return Ok(String::from(
+            "Ensure a and b are whole numbers, and that a is at most b",
+        ));
+    }
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    let uniform = uniform?;
+
+    Ok(format!(
+        "{}",
+        discrete_inverse_cdf(|x| uniform.cdf(x), area, min as f64)
+    ))
+}
+
+pub fn get_bernoulli_pdf(p: f64, x: u64) -> Result<String> {
+    let bernoulli = Bernoulli::new(p);
+
+    if bernoulli.is_err() {
+        return Ok(String::from(
+            "Ensure p and x are numbers, and that p is between 0 and 1",
+        ));
+    }
+
+    Ok(format!("{}", bernoulli?.pmf(x)))
+}
+
+pub fn get_bernoulli_cdf(p: f64, range: Range) -> Result<String> {
+    let bernoulli = Bernoulli::new(p);
+
+    if bernoulli.is_err() {
+        return Ok(String::from("Ensure p is a number between 0 and 1"));
+    }
+
+    let bernoulli = bernoulli?;
+
+    Ok(format_probability(range.probability(|x| bernoulli.cdf(x))))
+}
+
+pub fn get_inv_bernoulli(area: f64, p: f64) -> Result<String> {
+    let bernoulli = Bernoulli::new(p);
+
+    if bernoulli.is_err() {
+        return Ok(String::from(
+            "Ensure area and p are numbers, and that p is between 0 and 1",
+        ));
+    }
+
+    if !is_area(area) {
+        return Ok(String::from(AREA_MESSAGE));
+    }
+
+    let bernoulli = bernoulli?;
+
+    Ok(format!(
+        "{}",
+        discrete_inverse_cdf(|x| bernoulli.cdf(x), area, 0.0)
+    ))
+}
+
+/// Probability of seeing exactly `counts`, one count per outcome, in n trials
+pub fn get_multinomial_pdf(n: u64, probabilities: &[f64], counts: &[u64]) -> Result<String> {
+    let multinomial = Multinomial::new(probabilities, n);
+
+    if multinomial.is_err() || (probabilities.iter().sum::<f64>() - 1.0).abs() > 1e-9 {
+        return Ok(String::from(
+            "Ensure the probabilities are at least 0 and add up to 1",
+        ));
+    }
+
+    match multinomial?.checked_pmf(counts) {
+        Ok(probability) => Ok(format!("{}", probability)),
+        Err(_) => Ok(String::from(
+            "Ensure there is one count per probability, and that the counts add up to n",
+        )),
+    }
+}
+
+const AREA_MESSAGE: &str = "Ensure the area is between 0 and 1, not including either";
+
+fn is_area(area: f64) -> bool {
+    area > 0.0 && area < 1.0
+}
+
+/// Finds the x where `cdf(x)` reaches `area` by bisection, for distributions
+/// without a closed form inverse. `area` has to be strictly between 0 and 1.
+pub fn inverse_cdf(cdf: impl Fn(f64) -> f64, area: f64) -> f64 {
+    // Widen the bracket until it holds the answer, cdf(±∞) is 0 and 1 so this ends
+    let (mut lower, mut upper) = (-1.0_f64, 1.0_f64);
+    while cdf(lower) > area {
+        lower *= 2.0;
+    }
+    while cdf(upper) < area {
+        upper *= 2.0;
+    }
+
+    loop {
+        let middle = lower + (upper - lower) / 2.0;
+        // Stops once the bracket can't be split any further
+        if middle <= lower || middle >= upper {
+            return middle;
+        }
+        if cdf(middle) < area {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+}
+
+/// Finds the smallest whole x from `min` up where `cdf(x)` reaches `area`.
+/// `area` has to be strictly between 0 and 1.
+pub fn discrete_inverse_cdf(cdf: impl Fn(f64) -> f64, area: f64, min: f64) -> f64 {
+    // Sums of the pmf land a hair under round areas like 0.5, which shouldn't skip a value
+    let reaches = |x: f64| cdf(x) >= area - 1e-12;
+
+    if reaches(min) {
+        return min;
+    }
+
+    // Gallop up until the answer is passed, then close in on it
+    let mut step = 1.0;
+    while !reaches(min + step) {
+        step *= 2.0;
+    }
+
+    let (mut lower, mut upper) = (min + step / 2.0, min + step);
+    while upper - lower > 1.0 {
+        let middle = ((lower + upper) / 2.0).floor();
+        if reaches(middle) {
+            upper = middle;
+        } else {
+            lower = middle;
+        }
+    }
+    upper
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use statrs::distribution::{
+        Bernoulli, Binomial, Discrete, DiscreteUniform, Geometric, Hypergeometric,
+        NegativeBinomial, Poisson,
+    };
+
+    /// Ranges on and between whole numbers, at the edges of the support and past them
+    const RANGES: [Range; 19] = [
+        Range::AtMost(3.0),
+        Range::LessThan(3.0),
+        Range::AtLeast(3.0),
+        Range::MoreThan(3.0),
+        Range::Between(2.0, 5.0),
+        Range::Between(3.0, 3.0),
+        Range::Between(5.0, 2.0),
+        Range::AtMost(2.5),
+        Range::LessThan(2.5),
+        Range::AtLeast(2.5),
+        Range::MoreThan(2.5),
+        Range::Between(1.5, 4.5),
+        Range::AtLeast(0.0),
+        Range::LessThan(0.0),
+        Range::AtMost(-1.0),
+        Range::MoreThan(1000.0),
+        Range::Outside(2.0, 5.0),
+        Range::Outside(1.5, 4.5),
+        Range::Between(f64::NEG_INFINITY, 4.0),
+    ];
+
+    fn contains(range: Range, x: f64) -> bool {
+        match range {
+            Range::AtMost(bound) => x <= bound,
+            Range::LessThan(bound) => x < bound,
+            Range::AtLeast(bound) => x >= bound,
+            Range::MoreThan(bound) => x > bound,
+            Range::Between(lower, upper) => lower <= x && x <= upper,
+            Range::Outside(lower, upper) => x < lower || upper < x,
+        }
+    }
+
+    /// Adds up `pmf` over every whole number from `min` to `max` that falls in the range
+    fn pmf_sum(range: Range, min: i64, max: i64, pmf: impl Fn(i64) -> f64) -> f64 {
+        (min..=max)
+            .filter(|&x| contains(range, x as f64))
+            .map(pmf)
+            .sum()
+    }
+
+    fn assert_matches(output: Result<String>, expected: f64, range: Range) {
+        let output = output.unwrap();
+        let actual = output
+            .parse::<f64>()
+            .unwrap_or_else(|_| panic!("{:?} gave `{}`", range, output));
+
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{:?} gave {}, the pmf adds up to {}",
+            range,
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn binomial_ranges_match_pmf_sums() {
+        let binom = Binomial::new(0.3, 12).unwrap();
+
+        for &range in RANGES.iter() {
+            let expected = pmf_sum(range, 0, 12, |x| binom.pmf(x as u64));
+            assert_matches(get_binom_cdf(12, 0.3, range), expected, range);
+        }
+    }
+
+    #[test]
+    fn geometric_ranges_match_pmf_sums() {
+        let geo = Geometric::new(0.4).unwrap();
+
+        for &range in RANGES.iter() {
+            let expected = pmf_sum(range, 1, 2000, |x| geo.pmf(x as u64));
+            assert_matches(get_geo_cdf(0.4, range), expected, range);
+        }
+    }
+
+    #[test]
+    fn poisson_ranges_match_pmf_sums() {
+        let poisson = Poisson::new(3.5).unwrap();
+
+        for &range in RANGES.iter() {
+            let expected = pmf_sum(range, 0, 200, |x| poisson.pmf(x as u64));
+            assert_matches(get_poisson_cdf(3.5, range), expected, range);
+        }
+    }
+
+    #[test]
+    fn hypergeometric_ranges_match_pmf_sums() {
+        let hypergeo = Hypergeometric::new(40, 12, 9).unwrap();
+
+        for &range in RANGES.iter() {
+            let expected = pmf_sum(range, 0, 9, |x| hypergeo.pmf(x as u64));
+            assert_matches(get_hypergeo_cdf(40, 12, 9, range), expected, range);
+        }
+    }
+
+    #[test]
+    fn negative_binomial_ranges_match_pmf_sums() {
+        let neg_binom = NegativeBinomial::new(4.0, 0.6).unwrap();
+
+        for &range in RANGES.iter() {
+            let expected = pmf_sum(range, 0, 500, |x| neg_binom.pmf(x as u64));
+            assert_matches(get_neg_binom_cdf(4.0, 0.6, range), expected, range);
+        }
+    }
+
+    #[test]
+    fn discrete_uniform_ranges_match_pmf_sums() {
+        let uniform = DiscreteUniform::new(-2, 6).unwrap();
+
+        for &range in RANGES.iter() {
+            let expected = pmf_sum(range, -2, 6, |x| uniform.pmf(x));
+            assert_matches(get_discrete_uniform_cdf(-2, 6, range), expected, range);
+        }
+    }
+
+    #[test]
+    fn bernoulli_ranges_match_pmf_sums() {
+        let bernoulli = Bernoulli::new(0.25).unwrap();
+
+        for &range in RANGES.iter() {
+            let expected = pmf_sum(range, 0, 1, |x| bernoulli.pmf(x as u64));
+            assert_matches(get_bernoulli_cdf(0.25, range), expected, range);
+        }
+    }
+
+    #[test]
+    fn range_selectors_use_the_matching_bound() {
+        assert_eq!(Range::parse("≤", "", "4"), Some(Range::AtMost(4.0)));
+        assert_eq!(Range::parse("<", "", "4"), Some(Range::LessThan(4.0)));
+        assert_eq!(Range::parse(">=", "2", ""), Some(Range::AtLeast(2.0)));
+        assert_eq!(Range::parse(">", "2", "9"), Some(Range::MoreThan(2.0)));
+        assert_eq!(
+            Range::parse(" Between ", "2", "4"),
+            Some(Range::Between(2.0, 4.0))
+        );
+        assert_eq!(
+            Range::parse("≤", "4", ""),
+            Some(Range::AtMost(f64::INFINITY))
+        );
+        assert_eq!(
+            Range::parse("between", "", "-∞"),
+            Some(Range::Between(f64::NEG_INFINITY, f64::NEG_INFINITY))
+        );
+        assert_eq!(Range::parse("≤", "", "four"), None);
+        assert_eq!(Range::parse("=", "2", "2"), None);
+    }
+
+    #[test]
+    fn bounds_can_be_infinite_or_shortcuts() {
+        let both = Some(Range::Between(f64::NEG_INFINITY, f64::INFINITY));
+        assert_eq!(Range::parse_bounds("", ""), both);
+        assert_eq!(Range::parse_bounds("-inf", "∞"), both);
+        assert_eq!(Range::parse_bounds("-∞", "Infinity"), both);
+
+        assert_eq!(
+            Range::parse_bounds("left of 1.5", ""),
+            Some(Range::AtMost(1.5))
+        );
+        assert_eq!(
+            Range::parse_bounds("", "Right of -2"),
+            Some(Range::AtLeast(-2.0))
+        );
+        assert_eq!(
+            Range::parse_bounds("between -1 and 1", ""),
+            Some(Range::Between(-1.0, 1.0))
+        );
+        assert_eq!(
+            Range::parse_bounds("outside -1.96, 1.96", ""),
+            Some(Range::Outside(-1.96, 1.96))
+        );
+        assert_eq!(Range::parse_bounds("left of", ""), None);
+        assert_eq!(Range::parse_bounds("between 1", ""), None);
+        assert_eq!(Range::parse_bounds("1e99x", ""), None);
+    }
+
+    #[test]
+    fn normal_tails_add_up() {
+        let normal = |lower: &str, upper: &str| {
+            let range = Range::parse_bounds(lower, upper).unwrap();
+            get_normal_cdf(range, 0.0, 1.0)
+                .unwrap()
+                .parse::<f64>()
+                .unwrap()
+        };
+
+        assert_eq!(normal("", ""), 1.0);
+        assert!((normal("", "0") - 0.5).abs() < 1e-12);
+        assert!((normal("right of 0", "") - 0.5).abs() < 1e-12);
+
+        let inside = normal("between -1.96 and 1.96", "");
+        let outside = normal("outside -1.96 and 1.96", "");
+        assert!((inside - 0.95).abs() < 1e-4);
+        assert!((inside + outside - 1.0).abs() < 1e-12);
+    }
+
+    fn assert_close(output: Result<String>, expected: f64) {
+        let output = output.unwrap();
+        let actual = output.parse::<f64>().unwrap();
+
+        assert!(
+            ((actual - expected) / expected).abs() < 1e-9,
+            "gave {}, expected {}",
+            output,
+            expected
+        );
+    }
+
+    #[test]
+    fn far_upper_tails_keep_their_precision() {
+        let right_of = |x: f64| Range::AtLeast(x);
+
+        assert_close(
+            get_normal_cdf(right_of(8.0), 0.0, 1.0),
+            6.220960574271819e-16,
+        );
+        assert_close(
+            get_normal_cdf(right_of(37.0), 0.0, 1.0),
+            5.725571222525139e-300,
+        );
+        assert_close(
+            get_normal_cdf(Range::Between(8.0, 9.0), 0.0, 1.0),
+            6.219831985865866e-16,
+        );
+        assert_close(
+            get_normal_cdf(Range::Outside(-8.0, 8.0), 0.0, 1.0),
+            2.0 * 6.220960574271819e-16,
+        );
+
+        // With 2 df the χ2 tail is e^(-x/2), with 1 df t is a Cauchy and F(2, 2) is 1 / (1 + x)
+        assert_close(get_chi_square_cdf(right_of(1300.0), 2.0), (-650.0f64).exp());
+        assert_close(get_t_cdf(right_of(1e20), 1.0), 1e-20 / PI);
+        assert_close(get_f_cdf(right_of(1e20), 2.0, 2.0), 1.0 / (1.0 + 1e20));
+    }
+
+    #[test]
+    fn tiny_probabilities_use_scientific_notation() {
+        let output = get_normal_cdf(Range::AtLeast(8.0), 0.0, 1.0).unwrap();
+        assert!(output.contains("e-16"), "gave {}", output);
+
+        assert_eq!(
+            get_normal_cdf(Range::AtLeast(0.0), 0.0, 1.0).unwrap(),
+            "0.5"
+        );
+        assert_eq!(
+            get_normal_cdf(Range::AtLeast(100.0), 0.0, 1.0).unwrap(),
+            "0"
+        );
+    }
+}
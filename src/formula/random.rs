@@ -0,0 +1,350 @@
+use super::probability::{discrete_inverse_cdf, inverse_cdf};
+use crate::variables::Value;
+
+use {
+    anyhow::Result,
+    rand::{distributions::Open01, rngs::StdRng, Rng, SeedableRng},
+    statrs::{
+        distribution::{
+            Bernoulli, Beta, Binomial, Cauchy, ChiSquared, DiscreteUniform, Exponential,
+            FisherSnedecor, Gamma, Geometric, Hypergeometric, InverseCDF, LogNormal,
+            NegativeBinomial, Normal, Poisson, StudentsT, Uniform, Univariate, Weibull,
+        },
+        function::gamma::gamma,
+    },
+    std::f64::consts::PI,
+};
+
+/// Largest sample that can be drawn at once, bigger ones make the output unreadable anyway
+const MAX_SAMPLE_SIZE: u64 = 10_000;
+
+const SAMPLE_SIZE_MESSAGE: &str = "Ensure the sample size is a whole number from 1 to 10000";
+
+/// Names the Random Sample formula accepts, with the parameters each one takes
+pub const DISTRIBUTIONS: [(&str, &str); 20] = [
+    ("Normal", "µ, σ"),
+    ("t", "df"),
+    ("χ2", "df"),
+    ("F", "numerator df, denominator df"),
+    ("Exponential", "λ"),
+    ("Uniform", "a, b"),
+    ("Gamma", "α, β"),
+    ("Beta", "α, β"),
+    ("Lognormal", "µ of ln X, σ of ln X"),
+    ("Weibull", "k, λ"),
+    ("Cauchy", "x0, γ"),
+    ("Logistic", "µ, s"),
+    ("Scaled t", "µ, s, df"),
+    ("Binomial", "n, p"),
+    ("Geometric", "p"),
+    ("Poisson", "λ"),
+    ("Hypergeometric", "N, K, n"),
+    ("Negative Binomial", "r, p"),
+    ("Discrete Uniform", "a, b"),
+    ("Bernoulli", "p"),
+];
+
+/// Turns a uniform number between 0 and 1 into a draw from some distribution
+pub type Quantile = Box<dyn Fn(f64) -> f64>;
+
+pub fn get_rand_int(min: i64, max: i64, size: u64, seed: Option<u64>) -> Result<String> {
+    if min > max {
+        return Ok(String::from(
+            "Ensure a and b are whole numbers, and that a is at most b",
+        ));
+    }
+
+    sample(size, seed, |rng| rng.gen_range(min..=max) as f64)
+}
+
+pub fn get_rand_norm(mean: f64, std_dev: f64, size: u64, seed: Option<u64>) -> Result<String> {
+    match quantile("Normal", &[mean, std_dev]) {
+        Some(quantile) => sample(size, seed, |rng| quantile(rng.sample(Open01))),
+        None => Ok(String::from(
+            "Ensure µ and σ are numbers, and that σ is greater than 0",
+        )),
+    }
+}
+
+pub fn get_rand_bin(n: u64, p: f64, size: u64, seed: Option<u64>) -> Result<String> {
+    match quantile("Binomial", &[n as f64, p]) {
+        Some(quantile) => sample(size, seed, |rng| quantile(rng.sample(Open01))),
+        None => Ok(String::from(
+            "Ensure n is a whole number and p a probability between 0 and 1",
+        )),
+    }
+}
+
+pub fn get_random_sample(
+    distribution: &str,
+    parameters: &[f64],
+    size: u64,
+    seed: Option<u64>,
+) -> Result<String> {
+    match quantile(distribution, parameters) {
+        Some(quantile) => sample(size, seed, |rng| quantile(rng.sample(Open01))),
+        None => Ok(format!(
+            "Ensure the distribution is one of {} with its parameters in order",
+            DISTRIBUTIONS
+                .iter()
+                .map(|(name, parameters)| format!("{} ({})", name, parameters))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Draws `size` values and lists them along with the seed that reproduces them. Without a
+/// seed a fresh one is picked, so any sample can be drawn again later.
+fn sample(
+    size: u64,
+    seed: Option<u64>,
+    mut draw: impl FnMut(&mut StdRng) -> f64,
+) -> Result<String> {
+    if size == 0 || size > MAX_SAMPLE_SIZE {
+        return Ok(String::from(SAMPLE_SIZE_MESSAGE));
+    }
+
+    // Picked seeds are kept short, so they're easy to copy down and store exactly in a variable
+    let seed = seed.unwrap_or_else(|| rand::thread_rng().gen_range(0..1_000_000));
+    let mut rng = StdRng::seed_from_u64(seed);
+    let values: Vec<f64> = (0..size).map(|_| draw(&mut rng)).collect();
+    let mean = values.iter().sum::<f64>() / size as f64;
+
+    Ok(format!(
+        "Sample: {}\nMean: {}\nSeed: {}",
+        Value::List(values),
+        mean,
+        seed
+    ))
+}
+
+/// Reads the Seed input, which is left blank for a random one
+pub fn parse_seed(seed: &str) -> Option<Option<u64>> {
+    if seed.trim().is_empty() {
+        Some(None)
+    } else {
+        seed.trim().parse::<u64>().ok().map(Some)
+    }
+}
+
+/// The inverse cdf of a distribution from the Probability tab, or `None` if the name is unknown
+/// or the parameters are out of range
+pub fn quantile(distribution: &str, parameters: &[f64]) -> Option<Quantile> {
+    let name = distribution.trim().to_lowercase();
+
+    match (name.as_str(), parameters) {
+        ("normal", &[mean, std_dev]) => {
+            let normal = Normal::new(mean, std_dev).ok()?;
+            Some(Box::new(move |u| normal.inverse_cdf(u)))
+        }
+        ("t", &[df]) => continuous(StudentsT::new(0.0, 1.0, df).ok()?),
+        ("χ2" | "chi square" | "chi2", &[df]) => continuous(ChiSquared::new(df).ok()?),
+        ("f", &[df_1, df_2]) => continuous(FisherSnedecor::new(df_1, df_2).ok()?),
+        ("exponential", &[rate]) => continuous(Exponential::new(rate).ok()?),
+        ("uniform", &[min, max]) if min < max => continuous(Uniform::new(min, max).ok()?),
+        ("gamma", &[shape, rate]) => continuous(Gamma::new(shape, rate).ok()?),
+        ("beta", &[shape_a, shape_b]) => continuous(Beta::new(shape_a, shape_b).ok()?),
+        ("lognormal", &[mean, std_dev]) => continuous(LogNormal::new(mean, std_dev).ok()?),
+        ("weibull", &[shape, scale]) => continuous(Weibull::new(shape, scale).ok()?),
+        ("cauchy", &[location, scale]) => {
+            Cauchy::new(location, scale).ok()?;
+            Some(Box::new(move |u| location + scale * (PI * (u - 0.5)).tan()))
+        }
+        ("logistic", &[mean, scale]) if scale > 0.0 => {
+            Some(Box::new(move |u| mean + scale * (u / (1.0 - u)).ln()))
+        }
+        ("scaled t", &[location, scale, df]) => {
+            continuous(StudentsT::new(location, scale, df).ok()?)
+        }
+        ("binomial", &[n, p]) if is_whole(n) && n >= 0.0 => {
+            discrete(Binomial::new(p, n as u64).ok()?, 0.0)
+        }
+        ("geometric", &[p]) => discrete(Geometric::new(p).ok()?, 1.0),
+        ("poisson", &[lambda]) => discrete(Poisson::new(lambda).ok()?, 0.0),
+        ("hypergeometric", &[population, successes, draws])
+            if [population, successes, draws]
+                .iter()
+                .all(|&x| is_whole(x) && x >= 0.0) =>
+        {
+            let hypergeo =
+                Hypergeometric::new(population as u64, successes as u64, draws as u64).ok()?;
+            // Drawing more items than there are failures guarantees some successes
+            let min = (draws - (population - successes)).max(0.0);
+            discrete(hypergeo, min)
+        }
+        ("negative binomial", &[r, p]) => discrete(NegativeBinomial::new(r, p).ok()?, 0.0),
+        ("discrete uniform", &[min, max]) if is_whole(min) && is_whole(max) => {
+            discrete(DiscreteUniform::new(min as i64, max as i64).ok()?, min)
+        }
+        ("bernoulli", &[p]) => discrete(Bernoulli::new(p).ok()?, 0.0),
+        _ => None,
+    }
+}
+
+/// Mean and variance of a distribution that `quantile` accepts, or `None` if the variance is
+/// infinite or undefined, like the Cauchy's, and so the central limit theorem doesn't apply
+pub fn moments(distribution: &str, parameters: &[f64]) -> Option<(f64, f64)> {
+    let name = distribution.trim().to_lowercase();
+
+    match (name.as_str(), parameters) {
+        ("normal", &[mean, std_dev]) => Some((mean, std_dev.powi(2))),
+        ("t", &[df]) if df > 2.0 => Some((0.0, df / (df - 2.0))),
+        ("χ2" | "chi square" | "chi2", &[df]) => Some((df, 2.0 * df)),
+        ("f", &[df_1, df_2]) if df_2 > 4.0 => Some((
+            df_2 / (df_2 - 2.0),
+            2.0 * df_2.powi(2) * (df_1 + df_2 - 2.0) / (df_1 * (df_2 - 2.0).powi(2) * (df_2 - 4.0)),
+        )),
+        ("exponential", &[rate]) => Some((1.0 / rate, 1.0 / rate.powi(2))),
+        ("uniform", &[min, max]) => Some(((min + max) / 2.0, (max - min).powi(2) / 12.0)),
+        ("gamma", &[shape, rate]) => Some((shape / rate, shape / rate.powi(2))),
+        ("beta", &[a, b]) => Some((a / (a + b), a * b / ((a + b).powi(2) * (a + b + 1.0)))),
+        ("lognormal", &[mean, std_dev]) => {
+            let variance = std_dev.powi(2);
+            Some((
+                (mean + variance / 2.0).exp(),
+                (variance.exp() - 1.0) * (2.0 * mean + variance).exp(),
+            ))
+        }
+        ("weibull", &[shape, scale]) => {
+            let mean = scale * gamma(1.0 + 1.0 / shape);
+            Some((
+                mean,
+                scale.powi(2) * gamma(1.0 + 2.0 / shape) - mean.powi(2),
+            ))
+        }
+        ("logistic", &[mean, scale]) => Some((mean, (scale * PI).powi(2) / 3.0)),
+        ("scaled t", &[location, scale, df]) if df > 2.0 => {
+            Some((location, scale.powi(2) * df / (df - 2.0)))
+        }
+        ("binomial", &[n, p]) => Some((n * p, n * p * (1.0 - p))),
+        ("geometric", &[p]) => Some((1.0 / p, (1.0 - p) / p.powi(2))),
+        ("poisson", &[lambda]) => Some((lambda, lambda)),
+        ("hypergeometric", &[population, successes, draws]) if population > 1.0 => {
+            let p = successes / population;
+            Some((
+                draws * p,
+                draws * p * (1.0 - p) * (population - draws) / (population - 1.0),
+            ))
+        }
+        ("negative binomial", &[r, p]) => Some((r * (1.0 - p) / p, r * (1.0 - p) / p.powi(2))),
+        ("discrete uniform", &[min, max]) => {
+            Some(((min + max) / 2.0, ((max - min + 1.0).powi(2) - 1.0) / 12.0))
+        }
+        ("bernoulli", &[p]) => Some((p, p * (1.0 - p))),
+        _ => None,
+    }
+}
+
+fn continuous(distribution: impl Univariate<f64, f64> + 'static) -> Option<Quantile> {
+    Some(Box::new(move |u| inverse_cdf(|x| distribution.cdf(x), u)))
+}
+
+fn discrete<T>(distribution: impl Univariate<T, f64> + 'static, min: f64) -> Option<Quantile> {
+    Some(Box::new(move |u| {
+        discrete_inverse_cdf(|x| distribution.cdf(x), u, min)
+    }))
+}
+
+fn is_whole(x: f64) -> bool {
+    x.fract() == 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variables::{result_fields, Value};
+    use anyhow::Result;
+
+    /// The list on the Sample line of a Random formula's output
+    fn sample_of(output: Result<String>) -> Vec<f64> {
+        match result_fields(&output.unwrap()).into_iter().next() {
+            Some((label, Value::List(sample))) if label == "Sample" => sample,
+            other => panic!("expected a sample, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn the_same_seed_draws_the_same_sample() {
+        let gamma = || sample_of(get_random_sample("Gamma", &[2.0, 3.0], 50, Some(7)));
+        assert_eq!(gamma(), gamma());
+        assert_ne!(
+            gamma(),
+            sample_of(get_random_sample("Gamma", &[2.0, 3.0], 50, Some(8)))
+        );
+
+        let output = get_rand_int(1, 6, 5, None).unwrap();
+        let seed = result_fields(&output)
+            .into_iter()
+            .find(|(label, _)| label == "Seed")
+            .map(|(_, seed)| seed.to_string())
+            .unwrap();
+        assert_eq!(
+            sample_of(Ok(output)),
+            sample_of(get_rand_int(1, 6, 5, seed.parse().ok()))
+        );
+    }
+
+    #[test]
+    fn samples_stay_in_the_support() {
+        let dice = sample_of(get_rand_int(1, 6, 1000, Some(1)));
+        assert!(dice
+            .iter()
+            .all(|&x| x.fract() == 0.0 && (1.0..=6.0).contains(&x)));
+        assert!((1..=6).all(|face| dice.contains(&(face as f64))));
+
+        let heads = sample_of(get_rand_bin(10, 0.5, 1000, Some(2)));
+        assert!(heads
+            .iter()
+            .all(|&x| x.fract() == 0.0 && (0.0..=10.0).contains(&x)));
+
+        let dice = sample_of(get_random_sample(
+            "Discrete Uniform",
+            &[-2.0, 2.0],
+            500,
+            Some(3),
+        ));
+        assert!(dice.iter().all(|&x| (-2.0..=2.0).contains(&x)));
+        assert!(dice.contains(&-2.0) && dice.contains(&2.0));
+
+        let draws = sample_of(get_random_sample(
+            "hypergeometric",
+            &[10.0, 8.0, 5.0],
+            500,
+            Some(4),
+        ));
+        assert!(draws.iter().all(|&x| (3.0..=5.0).contains(&x)));
+    }
+
+    #[test]
+    fn sample_means_are_near_the_distribution_mean() {
+        let mean = |sample: Vec<f64>| sample.iter().sum::<f64>() / sample.len() as f64;
+
+        assert!((mean(sample_of(get_rand_norm(100.0, 15.0, 10000, Some(5)))) - 100.0).abs() < 1.0);
+        assert!(
+            (mean(sample_of(get_random_sample(
+                "Poisson",
+                &[3.5],
+                10000,
+                Some(6)
+            ))) - 3.5)
+                .abs()
+                < 0.1
+        );
+        assert!(
+            (mean(sample_of(get_random_sample("χ2", &[4.0], 10000, Some(7)))) - 4.0).abs() < 0.2
+        );
+    }
+
+    #[test]
+    fn unknown_distributions_and_sizes_are_explained() {
+        let output = get_random_sample("Zipf", &[1.0], 10, Some(1)).unwrap();
+        assert!(output.starts_with("Ensure the distribution is one of"));
+
+        let output = get_random_sample("Normal", &[0.0], 10, Some(1)).unwrap();
+        assert!(output.starts_with("Ensure the distribution is one of"));
+
+        let output = get_rand_int(1, 6, 0, Some(1)).unwrap();
+        assert!(output.starts_with("Ensure the sample size"));
+    }
+}
@@ -0,0 +1,791 @@
+use super::retrieve_formula;
+
+/// What a formula computes, how, and an example of using it
+pub struct Reference {
+    pub description: &'static str,
+    pub formula: &'static str,
+    pub example: &'static str,
+}
+
+/// What an input parameter means and which values it accepts
+pub struct Parameter {
+    pub description: &'static str,
+    pub range: &'static str,
+}
+
+/// A formula's reference card. Its parameters are looked up from the formula's own input
+/// labels, so the card always lists exactly the inputs the formula asks for.
+pub struct Card {
+    pub reference: Option<Reference>,
+    pub parameters: Vec<(String, Option<Parameter>)>,
+}
+
+pub fn retrieve_card(formula_name: &str) -> Card {
+    Card {
+        reference: match_formula_reference(formula_name),
+        parameters: retrieve_formula(formula_name)
+            .into_iter()
+            .map(|label| {
+                let parameter = match_parameter_reference(&label);
+                (label, parameter)
+            })
+            .collect(),
+    }
+}
+
+fn reference(description: &'static str, formula: &'static str, example: &'static str) -> Reference {
+    Reference {
+        description,
+        formula,
+        example,
+    }
+}
+
+fn parameter(description: &'static str, range: &'static str) -> Parameter {
+    Parameter { description, range }
+}
+
+fn match_formula_reference(formula_name: &str) -> Option<Reference> {
+    let reference = match formula_name {
+        // Probability Formulas
+        "Factorial (!)" => reference(
+            "The product of every whole number from 1 to n.",
+            "n! = n × (n - 1) × ... × 1",
+            "n = 5 gives 120",
+        ),
+        "Permutations" => reference(
+            "Ways to arrange r of n distinct items when order matters.",
+            "nPr = n! / (n - r)!",
+            "n = 5, r = 2 gives 20",
+        ),
+        "Combinations" => reference(
+            "Ways to choose r of n distinct items when order doesn't matter.",
+            "nCr = n! / (r! (n - r)!)",
+            "n = 5, r = 2 gives 10",
+        ),
+        "Normal Pdf" => reference(
+            "Height of the normal density curve at x.",
+            "f(x) = e^(-(x - µ)² / 2σ²) / (σ √(2π))",
+            "x = 0, µ = 0, σ = 1 gives 0.3989",
+        ),
+        "Normal Cdf" => reference(
+            "Probability that a normal variable falls between the bounds.",
+            "P(a ≤ X ≤ b) = Φ((b - µ) / σ) - Φ((a - µ) / σ)",
+            "-1.96 to 1.96, µ = 0, σ = 1 gives 0.95",
+        ),
+        "Inverse Normal" => reference(
+            "The value with the given area to its left under the normal curve.",
+            "x = µ + σ Φ⁻¹(Area)",
+            "Area = 0.975, µ = 0, σ = 1 gives 1.96",
+        ),
+        "t Pdf" => reference(
+            "Height of Student's t density curve at x.",
+            "f(x) = Γ((df + 1) / 2) / (√(df π) Γ(df / 2)) (1 + x² / df)^(-(df + 1) / 2)",
+            "x = 0, df = 10 gives 0.3891",
+        ),
+        "t Cdf" => reference(
+            "Probability that a t variable falls between the bounds.",
+            "P(a ≤ T ≤ b) = F(b) - F(a)",
+            "-2.228 to 2.228, df = 10 gives 0.95",
+        ),
+        "Inverse t" => reference(
+            "The value with the given area to its left under the t curve.",
+            "x = F⁻¹(Area), found numerically",
+            "Area = 0.975, df = 10 gives 2.228",
+        ),
+        "χ2 Pdf" => reference(
+            "Height of the χ² density curve at x.",
+            "f(x) = x^(df/2 - 1) e^(-x/2) / (2^(df/2) Γ(df / 2))",
+            "x = 2, df = 3 gives 0.2076",
+        ),
+        "χ2 Cdf" => reference(
+            "Probability that a χ² variable falls between the bounds.",
+            "P(a ≤ X ≤ b) = F(b) - F(a)",
+            "0 to 7.815, df = 3 gives 0.95",
+        ),
+        "Inverse χ2" => reference(
+            "The value with the given area to its left under the χ² curve.",
+            "x = F⁻¹(Area), found numerically",
+            "Area = 0.95, df = 3 gives 7.815",
+        ),
+        "Binomial Pdf" => reference(
+            "Probability of exactly x successes in n independent trials.",
+            "P(X = x) = nCx p^x (1 - p)^(n - x)",
+            "n = 10, p = 0.5, x = 5 gives 0.2461",
+        ),
+        "Binomial Cdf" => reference(
+            "Probability that the number of successes falls in a range.",
+            "P(a ≤ X ≤ b) = Σ nCx p^x (1 - p)^(n - x)",
+            "n = 10, p = 0.5, ≤ 5 gives 0.6230",
+        ),
+        "F Pdf" => reference(
+            "Height of the F density curve at x.",
+            "f(x) = √((d1 x)^d1 d2^d2 / (d1 x + d2)^(d1 + d2)) / (x B(d1 / 2, d2 / 2))",
+            "x = 1, d1 = 5, d2 = 10 gives 0.4955",
+        ),
+        "F Cdf" => reference(
+            "Probability that an F variable falls between the bounds.",
+            "P(a ≤ F ≤ b) = I(d1 b / (d1 b + d2)) - I(d1 a / (d1 a + d2))",
+            "0 to 3.326, d1 = 5, d2 = 10 gives 0.95",
+        ),
+        "Geometric Pdf" => reference(
+            "Probability that the first success happens on trial x.",
+            "P(X = x) = (1 - p)^(x - 1) p",
+            "p = 0.2, x = 3 gives 0.128",
+        ),
+        "Geometric Cdf" => reference(
+            "Probability that the first success happens within a range of trials.",
+            "P(a ≤ X ≤ b) = (1 - p)^(a - 1) - (1 - p)^b",
+            "p = 0.2, between 1 and 3 gives 0.488",
+        ),
+        "Poisson Pdf" => reference(
+            "Probability of exactly x events when λ are expected.",
+            "P(X = x) = λ^x e^(-λ) / x!",
+            "λ = 3, x = 2 gives 0.2240",
+        ),
+        "Poisson Cdf" => reference(
+            "Probability that the number of events falls in a range.",
+            "P(a ≤ X ≤ b) = Σ λ^x e^(-λ) / x!",
+            "λ = 3, ≤ 2 gives 0.4232",
+        ),
+
+        "Hypergeometric Pdf" => reference(
+            "Probability of exactly x successes in n draws without replacement.",
+            "P(X = x) = KCx (N - K)C(n - x) / NCn",
+            "N = 50, K = 5, n = 10, x = 1 gives 0.4313",
+        ),
+        "Hypergeometric Cdf" => reference(
+            "Probability that the successes drawn without replacement fall in a range.",
+            "P(a ≤ X ≤ b) = Σ KCx (N - K)C(n - x) / NCn",
+            "N = 50, K = 5, n = 10, ≤ 1 gives 0.7419",
+        ),
+        "Inverse Hypergeometric" => reference(
+            "The smallest number of successes with at least the given area at or below it.",
+            "x = min { x : P(X ≤ x) ≥ Area }",
+            "Area = 0.5, N = 50, K = 5, n = 10 gives 1",
+        ),
+        "Negative Binomial Pdf" => reference(
+            "Probability of exactly x failures before the r-th success.",
+            "P(X = x) = (x + r - 1)Cx p^r (1 - p)^x",
+            "r = 3, p = 0.5, x = 2 gives 0.1875",
+        ),
+        "Negative Binomial Cdf" => reference(
+            "Probability that the failures before the r-th success fall in a range.",
+            "P(a ≤ X ≤ b) = Σ (x + r - 1)Cx p^r (1 - p)^x",
+            "r = 3, p = 0.5, ≤ 2 gives 0.5",
+        ),
+        "Inverse Negative Binomial" => reference(
+            "The smallest number of failures with at least the given area at or below it.",
+            "x = min { x : P(X ≤ x) ≥ Area }",
+            "Area = 0.5, r = 3, p = 0.5 gives 2",
+        ),
+        "Discrete Uniform Pdf" => reference(
+            "Probability of x when every whole number from a to b is equally likely.",
+            "P(X = x) = 1 / (b - a + 1)",
+            "a = 1, b = 6, x = 3 gives 0.1667",
+        ),
+        "Discrete Uniform Cdf" => reference(
+            "Probability that a discrete uniform variable falls in a range.",
+            "P(c ≤ X ≤ d) = (d - c + 1) / (b - a + 1), within [a, b]",
+            "a = 1, b = 6, between 2 and 4 gives 0.5",
+        ),
+        "Inverse Discrete Uniform" => reference(
+            "The smallest value with at least the given area at or below it.",
+            "x = a + ⌈Area (b - a + 1)⌉ - 1",
+            "Area = 0.5, a = 1, b = 6 gives 3",
+        ),
+        "Bernoulli Pdf" => reference(
+            "Probability that a single trial is a success (1) or a failure (0).",
+            "P(X = 1) = p, P(X = 0) = 1 - p",
+            "p = 0.3, x = 1 gives 0.3",
+        ),
+        "Bernoulli Cdf" => reference(
+            "Probability that a single trial's outcome falls in a range.",
+            "P(X ≤ 0) = 1 - p, P(X ≤ 1) = 1",
+            "p = 0.3, ≤ 0 gives 0.7",
+        ),
+        "Inverse Bernoulli" => reference(
+            "The smallest outcome with at least the given area at or below it.",
+            "x = 0 if Area ≤ 1 - p, otherwise 1",
+            "Area = 0.8, p = 0.3 gives 1",
+        ),
+        "Multinomial Pdf" => reference(
+            "Probability of exactly the given count of each outcome in n trials.",
+            "P = n! / (x1! ... xk!) p1^x1 ... pk^xk",
+            "n = 10, p 0.2,0.3,0.5, counts 2,3,5 gives 0.0851",
+        ),
+        "Exponential Pdf" => reference(
+            "Height of the exponential density curve at x, the waiting time between events.",
+            "f(x) = λ e^(-λx), x ≥ 0",
+            "x = 1, λ = 0.5 gives 0.3033",
+        ),
+        "Exponential Cdf" => reference(
+            "Probability that an exponential variable falls between the bounds.",
+            "P(a ≤ X ≤ b) = e^(-λa) - e^(-λb)",
+            "0 to 2, λ = 0.5 gives 0.6321",
+        ),
+        "Inverse Exponential" => reference(
+            "The value with the given area to its left under the exponential curve.",
+            "x = -ln(1 - Area) / λ",
+            "Area = 0.5, λ = 0.5 gives 1.386",
+        ),
+        "Uniform Pdf" => reference(
+            "Height of the flat density between a and b.",
+            "f(x) = 1 / (b - a), a ≤ x ≤ b",
+            "x = 3, a = 0, b = 10 gives 0.1",
+        ),
+        "Uniform Cdf" => reference(
+            "Probability that a uniform variable falls between the bounds.",
+            "P(c ≤ X ≤ d) = (d - c) / (b - a), within [a, b]",
+            "2 to 7, a = 0, b = 10 gives 0.5",
+        ),
+        "Inverse Uniform" => reference(
+            "The value with the given area to its left under the uniform density.",
+            "x = a + Area (b - a)",
+            "Area = 0.25, a = 0, b = 10 gives 2.5",
+        ),
+        "Gamma Pdf" => reference(
+            "Height of the gamma density curve at x.",
+            "f(x) = β^α x^(α - 1) e^(-βx) / Γ(α)",
+            "x = 1, α = 2, β = 1 gives 0.3679",
+        ),
+        "Gamma Cdf" => reference(
+            "Probability that a gamma variable falls between the bounds.",
+            "P(a ≤ X ≤ b) = P(α, βb) - P(α, βa), P the regularized gamma function",
+            "0 to 2, α = 2, β = 1 gives 0.5940",
+        ),
+        "Inverse Gamma" => reference(
+            "The value with the given area to its left under the gamma curve.",
+            "x = F⁻¹(Area), found numerically",
+            "Area = 0.5, α = 2, β = 1 gives 1.678",
+        ),
+        "Beta Pdf" => reference(
+            "Height of the beta density curve at x, a distribution of proportions.",
+            "f(x) = x^(α - 1) (1 - x)^(β - 1) / B(α, β), 0 ≤ x ≤ 1",
+            "x = 0.5, α = 2, β = 3 gives 1.5",
+        ),
+        "Beta Cdf" => reference(
+            "Probability that a beta variable falls between the bounds.",
+            "P(a ≤ X ≤ b) = I(b; α, β) - I(a; α, β)",
+            "0 to 0.5, α = 2, β = 3 gives 0.6875",
+        ),
+        "Inverse Beta" => reference(
+            "The value with the given area to its left under the beta curve.",
+            "x = F⁻¹(Area), found numerically",
+            "Area = 0.5, α = 2, β = 3 gives 0.3857",
+        ),
+        "Lognormal Pdf" => reference(
+            "Height of the density of X when ln X is normal.",
+            "f(x) = e^(-(ln x - µ)² / 2σ²) / (x σ √(2π))",
+            "x = 1, µ = 0, σ = 1 gives 0.3989",
+        ),
+        "Lognormal Cdf" => reference(
+            "Probability that a lognormal variable falls between the bounds.",
+            "P(a ≤ X ≤ b) = Φ((ln b - µ) / σ) - Φ((ln a - µ) / σ)",
+            "0 to 2, µ = 0, σ = 1 gives 0.7559",
+        ),
+        "Inverse Lognormal" => reference(
+            "The value with the given area to its left under the lognormal curve.",
+            "x = e^(µ + σ Φ⁻¹(Area))",
+            "Area = 0.5, µ = 0, σ = 1 gives 1",
+        ),
+        "Weibull Pdf" => reference(
+            "Height of the Weibull density curve at x, common for lifetimes.",
+            "f(x) = (k / λ) (x / λ)^(k - 1) e^(-(x / λ)^k)",
+            "x = 1, k = 2, λ = 1 gives 0.7358",
+        ),
+        "Weibull Cdf" => reference(
+            "Probability that a Weibull variable falls between the bounds.",
+            "P(a ≤ X ≤ b) = e^(-(a / λ)^k) - e^(-(b / λ)^k)",
+            "0 to 1, k = 2, λ = 1 gives 0.6321",
+        ),
+        "Inverse Weibull" => reference(
+            "The value with the given area to its left under the Weibull curve.",
+            "x = λ (-ln(1 - Area))^(1 / k)",
+            "Area = 0.5, k = 2, λ = 1 gives 0.8326",
+        ),
+        "Cauchy Pdf" => reference(
+            "Height of the Cauchy density curve at x, which has no mean.",
+            "f(x) = 1 / (πγ (1 + ((x - x0) / γ)²))",
+            "x = 0, x0 = 0, γ = 1 gives 0.3183",
+        ),
+        "Cauchy Cdf" => reference(
+            "Probability that a Cauchy variable falls between the bounds.",
+            "F(x) = 1/2 + arctan((x - x0) / γ) / π",
+            "-1 to 1, x0 = 0, γ = 1 gives 0.5",
+        ),
+        "Inverse Cauchy" => reference(
+            "The value with the given area to its left under the Cauchy curve.",
+            "x = x0 + γ tan(π (Area - 1/2))",
+            "Area = 0.75, x0 = 0, γ = 1 gives 1",
+        ),
+        "Logistic Pdf" => reference(
+            "Height of the logistic density curve at x.",
+            "f(x) = e^(-z) / (s (1 + e^(-z))²), z = (x - µ) / s",
+            "x = 0, µ = 0, s = 1 gives 0.25",
+        ),
+        "Logistic Cdf" => reference(
+            "Probability that a logistic variable falls between the bounds.",
+            "F(x) = 1 / (1 + e^(-(x - µ) / s))",
+            "-2 to 2, µ = 0, s = 1 gives 0.7616",
+        ),
+        "Inverse Logistic" => reference(
+            "The value with the given area to its left under the logistic curve.",
+            "x = µ + s ln(Area / (1 - Area))",
+            "Area = 0.9, µ = 0, s = 1 gives 2.197",
+        ),
+        "Scaled t Pdf" => reference(
+            "Height of Student's t density shifted to µ and stretched by s.",
+            "f(x) = t pdf((x - µ) / s) / s",
+            "x = 10, µ = 10, s = 2, df = 5 gives 0.1898",
+        ),
+        "Scaled t Cdf" => reference(
+            "Probability that a scaled t variable falls between the bounds.",
+            "P(a ≤ X ≤ b) = F((b - µ) / s) - F((a - µ) / s)",
+            "6 to 14, µ = 10, s = 2, df = 5 gives 0.8981",
+        ),
+        "Inverse Scaled t" => reference(
+            "The value with the given area to its left under the scaled t curve.",
+            "x = µ + s F⁻¹(Area), found numerically",
+            "Area = 0.975, µ = 10, s = 2, df = 5 gives 15.14",
+        ),
+
+        // Random Formulas
+        "randInt" => reference(
+            "Random whole numbers from a to b, each equally likely.",
+            "Uniform on a, a + 1, ..., b",
+            "a = 1, b = 6, Sample Size = 10 rolls a die ten times",
+        ),
+        "randNorm" => reference(
+            "Random draws from a normal distribution.",
+            "x = µ + σ Φ⁻¹(u), u uniform on (0, 1)",
+            "µ = 100, σ = 15, Sample Size = 30 gives thirty IQ scores",
+        ),
+        "randBin" => reference(
+            "Random success counts of n trials each.",
+            "Smallest x with P(X ≤ x) ≥ u, u uniform on (0, 1)",
+            "n = 10, p = 0.5, Sample Size = 20 counts heads in 20 rounds of 10 flips",
+        ),
+        "Random Sample" => reference(
+            "Random draws from any distribution on the Probability tab.",
+            "x = F⁻¹(u), u uniform on (0, 1)",
+            "Gamma with parameters 2, 3 and Sample Size = 50 gives fifty gamma draws",
+        ),
+        "Sampling Distribution" => reference(
+            "Means of many samples of size n, next to the normal curve the CLT predicts.",
+            "x̄ ≈ N(µ, σ / √n) for large n",
+            "Exponential with parameter 1 and n = 30 is close to N(1, 0.1826)",
+        ),
+
+        // Intervals Formulas
+        "z Interval" => reference(
+            "Confidence interval for a mean when σ is known.",
+            "x̄ ± z* σ / √n",
+            "σ = 2, x̄ = 10, n = 25, C = 95 gives (9.216, 10.784)",
+        ),
+        "t Interval" => reference(
+            "Confidence interval for a mean when σ is estimated by Sx.",
+            "x̄ ± t* Sx / √n, df = n - 1",
+            "x̄ = 10, Sx = 2, n = 25, C = 95 gives (9.174, 10.826)",
+        ),
+        "2-Sample z Interval" => reference(
+            "Confidence interval for µ1 - µ2 when both σ are known.",
+            "(x̄1 - x̄2) ± z* √(σ1² / n1 + σ2² / n2)",
+            "σ1 = 2, σ2 = 3, x̄1 = 10, n1 = 30, x̄2 = 9, n2 = 40, C = 95",
+        ),
+        "2-Sample t Interval" => reference(
+            "Confidence interval for µ1 - µ2 from two sample means.",
+            "(x̄1 - x̄2) ± t* √(Sx1² / n1 + Sx2² / n2)",
+            "x̄1 = 10, Sx1 = 2, n1 = 30, x̄2 = 9, Sx2 = 3, n2 = 40, C = 95",
+        ),
+        "1-Prop z Interval" => reference(
+            "Confidence interval for a population proportion.",
+            "p̂ ± z* √(p̂ (1 - p̂) / n), p̂ = x / n",
+            "x = 40, n = 100, C = 95 gives (0.304, 0.496)",
+        ),
+        "2-Prop z Interval" => reference(
+            "Confidence interval for the difference of two proportions.",
+            "(p̂1 - p̂2) ± z* √(p̂1 (1 - p̂1) / n1 + p̂2 (1 - p̂2) / n2)",
+            "x1 = 40, n1 = 100, x2 = 30, n2 = 100, C = 95",
+        ),
+        "Paired t Interval" => reference(
+            "Confidence interval for the mean change from before to after.",
+            "d̄ ± t* Sd / √n, d = after - before, df = n - 1",
+            "Student's sleep data, C = 95 gives (-2.46, -0.70) for group 1 - group 2",
+        ),
+        "χ2 Variance Interval" => reference(
+            "Confidence interval for a population variance, and its square root for σ.",
+            "((n - 1) Sx² / χ2 upper, (n - 1) Sx² / χ2 lower), df = n - 1, for normal data",
+            "Sx = 3, n = 20, C = 95 gives σ² in (5.21, 19.20), σ in (2.28, 4.38)",
+        ),
+        "2-Sample F Interval" => reference(
+            "Confidence interval for the ratio of two population variances, σ1² / σ2².",
+            "(F / F upper, F / F lower), F = Sx1² / Sx2², df = (n1 - 1, n2 - 1)",
+            "Sx1 = 3, n1 = 20, Sx2 = 2, n2 = 25, C = 95 gives (0.959, 5.52)",
+        ),
+        "Bootstrap Interval" => reference(
+            "Confidence intervals for a statistic from resampling the data B times.",
+            "Percentile: middle C% of θ*, basic: 2θ̂ - those, BCa: corrected for bias and skew",
+            "Mean of 15 values with x̄ = 5.2, B = 4000, C = 95 gives about (4.3, 6.2)",
+        ),
+
+        // Tests Formulas
+        "z Test" => reference(
+            "Tests a mean against µ0 when σ is known.",
+            "z = (x̄ - µ0) / (σ / √n)",
+            "µ0 = 10, σ = 2, x̄ = 10.8, n = 25, ≠ gives z = 2",
+        ),
+        "t Test" => reference(
+            "Tests a mean against µ0 when σ is estimated by Sx.",
+            "t = (x̄ - µ0) / (Sx / √n), df = n - 1",
+            "µ0 = 10, x̄ = 10.8, Sx = 2, n = 25, ≠ gives t = 2",
+        ),
+        "2-Sample z Test" => reference(
+            "Tests whether two means differ when both σ are known.",
+            "z = (x̄1 - x̄2) / √(σ1² / n1 + σ2² / n2)",
+            "σ1 = 2, σ2 = 3, x̄1 = 10, n1 = 30, x̄2 = 9, n2 = 40, ≠",
+        ),
+        "2-Sample t Test" => reference(
+            "Tests whether two means differ from two sample means.",
+            "t = (x̄1 - x̄2) / √(Sx1² / n1 + Sx2² / n2)",
+            "x̄1 = 10, Sx1 = 2, n1 = 30, x̄2 = 9, Sx2 = 3, n2 = 40, ≠, not pooled",
+        ),
+        "1-Prop z Test" => reference(
+            "Tests a population proportion against P0.",
+            "z = (p̂ - P0) / √(P0 (1 - P0) / n)",
+            "P0 = 0.5, x = 60, n = 100, > gives z = 2",
+        ),
+        "2-Prop z Test" => reference(
+            "Tests whether two proportions differ, using the pooled proportion.",
+            "z = (p̂1 - p̂2) / √(p̂ (1 - p̂) (1 / n1 + 1 / n2))",
+            "x1 = 40, n1 = 100, x2 = 30, n2 = 100, ≠",
+        ),
+        "χ2 GOF" => reference(
+            "Tests whether observed counts fit the expected counts.",
+            "χ² = Σ (O - E)² / E",
+            "Observed 10,20,30, Expected 20,20,20, df = 2 gives χ² = 10",
+        ),
+        "χ2 2-way Test" => reference(
+            "Tests whether the rows and columns of a table are independent.",
+            "χ² = Σ (O - E)² / E, E = row total × column total / total",
+            "Observed 10,20;30,40",
+        ),
+        "2-Sample F Test" => reference(
+            "Tests whether two population variances differ.",
+            "F = Sx1² / Sx2², df = (n1 - 1, n2 - 1)",
+            "Sx1 = 3, n1 = 20, Sx2 = 2, n2 = 25, ≠ gives F = 2.25",
+        ),
+        "ANOVA" => reference(
+            "Tests whether the means of several groups differ.",
+            "F = MS between / MS within",
+            "3 groups, n 10,10,10, x̄ 5,6,7, Sx 1,1,1",
+        ),
+        "Paired t Test" => reference(
+            "Tests the mean change from before to after against µ0.",
+            "t = (d̄ - µ0) / (Sd / √n), d = after - before, df = n - 1",
+            "Student's sleep data, µ0 = 0, ≠ gives t = -4.06, p = 0.0028",
+        ),
+        "Permutation Test" => reference(
+            "Tests whether two groups differ by shuffling their values between them.",
+            "p = share of arrangements with a difference as extreme as the observed one",
+            "Means of 1, 2, 3 and 4, 5, 6 with < gives p = 1/20 = 0.05, from all 20",
+        ),
+        "Sign Test" => reference(
+            "Tests a median against M0 by counting the values above it.",
+            "Above M0 ~ Binomial(n, 0.5), n leaves out values equal to M0",
+            "10 values, 8 above, 1 below and 1 equal to M0 = 1, ≠ gives p = 0.0391",
+        ),
+        "Wilcoxon Signed-Rank Test" => reference(
+            "Tests a median against M0 with the ranks of |x - M0|, for symmetric data.",
+            "W+ = sum of the ranks of values above M0, exact for n ≤ 50 without ties",
+            "1.83, 0.5, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.3, M0 = 1, ≠ gives p = 0.0117",
+        ),
+        "Mann-Whitney U Test" => reference(
+            "Tests whether one group's values tend to be larger, with the ranks of both.",
+            "U = R1 - n1 (n1 + 1) / 2, exact for n1 + n2 ≤ 50 without ties",
+            "10 and 5 values with U = 35, > gives p = 0.1272",
+        ),
+        "Kruskal-Wallis Test" => reference(
+            "Tests whether several groups differ, with the ranks of all values together.",
+            "H = 12 / (N (N + 1)) Σ Ri² / ni - 3 (N + 1), corrected for ties",
+            "6.4, 6.8, 7.2, 8.3; 2.5, 3.7, 4.9, 5.4, 5.9; 1.3, 4.1, 4.9, 5.2 gives p = 0.0076",
+        ),
+        "Friedman Test" => reference(
+            "Tests whether treatments differ when every subject gets each one.",
+            "Q = 12 / (n k (k + 1)) Σ Rj² - 3 n (k + 1), ranks within each row",
+            "7, 9, 8; 6, 5, 7; 9, 7, 6; 8, 5, 6; 6, 4, 5 gives Q = 1.6, p = 0.5216",
+        ),
+        "χ2 Variance Test" => reference(
+            "Tests a population variance against σ0², for normal data.",
+            "χ2 = (n - 1) Sx² / σ0², df = n - 1",
+            "σ0 = 2, Sx = 3, n = 21, > gives χ2 = 45, p = 0.0011",
+        ),
+        "Levene Test" => reference(
+            "Tests whether several groups have equal variances, as ANOVA assumes.",
+            "ANOVA F on |x - x̄i|, the distances from each group's mean",
+            "R's InsectSprays counts, 6 groups of 12, give F = 6.455, p = 0.00006",
+        ),
+        "Brown-Forsythe Test" => reference(
+            "Levene's test with group medians, robust to skewed data.",
+            "ANOVA F on |x - Mi|, the distances from each group's median",
+            "R's InsectSprays counts, 6 groups of 12, give F = 3.821, p = 0.0042",
+        ),
+        "Bartlett Test" => reference(
+            "Tests whether several groups have equal variances, for normal data.",
+            "χ2 = ((N - k) ln Sp² - Σ (ni - 1) ln Si²) / C, df = k - 1",
+            "R's InsectSprays counts, 6 groups of 12, give χ2 = 25.96, p = 0.00009",
+        ),
+        "Shapiro-Wilk Test" => reference(
+            "Tests whether data come from a normal distribution, small p means they don't.",
+            "W = (Σ ai x(i))² / Σ(x - x̄)², Royston's weights and p value, 3 ≤ n ≤ 5000",
+            "148, 154, 158, 160, 161, 162, 166, 170, 182, 195, 236 gives W = 0.7888, p = 0.0067",
+        ),
+        "Anderson-Darling Test" => reference(
+            "Tests normality, weighting the tails more than Kolmogorov-Smirnov.",
+            "A² = -n - Σ (2i - 1) (ln Φ(z(i)) + ln(1 - Φ(z(n+1-i)))) / n, µ and σ fitted",
+            "The 11 weights of the Shapiro-Wilk example give A² = 0.9468, p = 0.0105",
+        ),
+        "Kolmogorov-Smirnov Test" => reference(
+            "Tests normality by the largest gap between the data's and a normal's cdf.",
+            "D = max |Fn(x) - Φ((x - µ) / σ)|, Lilliefors' p value when µ and σ are fitted",
+            "The 11 weights give D = 0.2592, p = 0.0374 fitted, p = 0.5628 against N(170, 25)",
+        ),
+        "Jarque-Bera Test" => reference(
+            "Tests normality from how skewed and heavy tailed the data are.",
+            "JB = n / 6 (S² + (K - 3)² / 4), compared to χ2 with df = 2",
+            "The 11 weights give JB = 6.983, p = 0.0305",
+        ),
+
+        // Regressions Formulas
+        "Linear Regression (mx+b)" => reference(
+            "Least squares line through the points, as y = mx + b.",
+            "m = Σ(x - x̄)(y - ȳ) / Σ(x - x̄)², b = ȳ - m x̄",
+            "X 1,2,3,4, Y 2,4,5,8",
+        ),
+        "Linear Regression (a+bx)" => reference(
+            "Least squares line through the points, as y = a + bx.",
+            "b = Σ(x - x̄)(y - ȳ) / Σ(x - x̄)², a = ȳ - b x̄",
+            "X 1,2,3,4, Y 2,4,5,8",
+        ),
+        "Median-Median Line" => reference(
+            "A line resistant to outliers, fit through the medians of three groups of points.",
+            "Slope through the outer group medians, shifted a third of the way to the middle one",
+            "X 1,2,3,4,5,6, Y 2,4,5,8,9,13",
+        ),
+        "Quadratic Regression" => reference(
+            "Least squares parabola through the points.",
+            "y = ax² + bx + c",
+            "X 1,2,3,4, Y 1,4,9,16",
+        ),
+        "Cubic Regression" => reference(
+            "Least squares cubic through the points.",
+            "y = ax³ + bx² + cx + d",
+            "X 1,2,3,4,5, Y 1,8,27,64,125",
+        ),
+        "Quartic Regression" => reference(
+            "Least squares quartic through the points.",
+            "y = ax⁴ + bx³ + cx² + dx + e",
+            "X 1,2,3,4,5,6, Y 1,16,81,256,625,1296",
+        ),
+
+        _ => return None,
+    };
+    Some(reference)
+}
+
+fn match_parameter_reference(label: &str) -> Option<Parameter> {
+    let parameter = match label {
+        "n" => parameter("Sample size, or number of items", "Whole number ≥ 0"),
+        "r" => parameter("Number of items chosen", "Whole number, 0 ≤ r ≤ n"),
+        "x" => parameter(
+            "Value to evaluate at",
+            "Any number in the distribution's support",
+        ),
+        "µ" => parameter("Mean of the distribution", "Any number"),
+        "σ" => parameter("Standard deviation", "Number > 0"),
+        "Range" => parameter(
+            "Which values to add up, ≤ and < use the Upper Bound, ≥ and > the Lower Bound",
+            "≤, <, ≥, >, between or outside, between includes both bounds",
+        ),
+        "Lower Bound" => parameter(
+            "Smallest value of the range, blank for -∞. Can instead be left of x, right of x, \
+            between a and b or outside a and b.",
+            "Any number ≤ Upper Bound, or -inf",
+        ),
+        "Upper Bound" => parameter(
+            "Largest value of the range, blank for ∞",
+            "Any number ≥ Lower Bound, or inf",
+        ),
+        "Area" => parameter("Area to the left of the value", "Number between 0 and 1"),
+        "Deg of Freedom, df" => parameter("Degrees of freedom", "Number > 0"),
+        "Num Trials, n" => parameter("Number of independent trials", "Whole number ≥ 0"),
+        "Prob Success, p" => parameter("Chance of success on each trial", "Number between 0 and 1"),
+        "Numerator df" => parameter("Degrees of freedom of the numerator", "Number > 0"),
+        "Denominator df" => parameter("Degrees of freedom of the denominator", "Number > 0"),
+        "λ" => parameter("Expected number of events", "Number > 0"),
+        "Population, N" => parameter("Number of items to draw from", "Whole number ≥ 0"),
+        "Successes in Pop, K" => parameter("Successes among the population", "Whole number ≤ N"),
+        "Draws, n" => parameter("Items drawn without replacement", "Whole number ≤ N"),
+        "Successes Needed, r" => parameter("Successes to wait for", "Number > 0"),
+        "Failures, x" => parameter("Failures before the r-th success", "Whole number ≥ 0"),
+        "Probability List" => {
+            parameter("Chance of each outcome", "Comma separated, adding up to 1")
+        }
+        "Count List" => parameter(
+            "How often each outcome happened",
+            "Comma separated, adding up to n",
+        ),
+        "Rate, λ" => parameter("Events per unit of time", "Number > 0"),
+        "Minimum, a" => parameter("Smallest possible value", "Any number < b"),
+        "Maximum, b" => parameter("Largest possible value", "Any number > a"),
+        "Shape, α" => parameter("First shape parameter", "Number > 0"),
+        "Shape, β" => parameter("Second shape parameter", "Number > 0"),
+        "Rate, β" => parameter("Rate, one over the scale", "Number > 0"),
+        "µ of ln X" => parameter("Mean of the variable's logarithm", "Any number"),
+        "σ of ln X" => parameter(
+            "Standard deviation of the variable's logarithm",
+            "Number > 0",
+        ),
+        "Shape, k" => parameter(
+            "Below 1 the failure rate falls, above 1 it rises",
+            "Number > 0",
+        ),
+        "Scale, λ" => parameter("Stretches the distribution along x", "Number > 0"),
+        "Location, x0" => parameter("Center of the distribution", "Any number"),
+        "Scale, γ" => parameter("Half the width of the middle 50%", "Number > 0"),
+        "Scale, s" => parameter("Stretches the distribution around µ", "Number > 0"),
+        "Sample Size" => parameter("How many values to draw", "Whole number from 1 to 10000"),
+        "Size of Each Sample" => parameter(
+            "How many values are averaged into each sample mean",
+            "Whole number from 1 to 1000",
+        ),
+        "Seed" => parameter(
+            "Starting point of the generator, the same seed draws the same values. Blank picks \
+            one at random and shows it.",
+            "Whole number ≥ 0, or blank",
+        ),
+        "Distribution" => parameter(
+            "Name of a distribution on the Probability tab",
+            "Normal, t, χ2, F, Exponential, Uniform, Gamma, Beta, Lognormal, Weibull, Cauchy, \
+            Logistic, Scaled t, Binomial, Geometric, Poisson, Hypergeometric, Negative Binomial, \
+            Discrete Uniform or Bernoulli",
+        ),
+        "Parameter List" => parameter(
+            "The distribution's parameters, in the order its Pdf takes them",
+            "Comma separated numbers",
+        ),
+        "x̄" => parameter("Sample mean", "Any number"),
+        "Sx" => parameter("Sample standard deviation", "Number > 0"),
+        "C Level" => parameter(
+            "Confidence level, as a percentage",
+            "Number between 0 and 100",
+        ),
+        "Statistic" => parameter(
+            "What to estimate, correlation and difference use both lists",
+            "Mean, Median, SD, Correlation or Difference",
+        ),
+        "Data List" => parameter("Values of the sample", "Comma separated numbers"),
+        "Second List" => parameter(
+            "Paired y values for a correlation, or a second sample for a difference of means",
+            "Comma separated numbers, or blank",
+        ),
+        "Resamples, B" => parameter(
+            "How many times the data is resampled with replacement",
+            "Whole number from 100 to 100000",
+        ),
+        "σ1" | "σ2" => parameter("Known population standard deviation", "Number > 0"),
+        "x̄1" | "x̄2" => parameter("Sample mean of the group", "Any number"),
+        "n1" | "n2" => parameter("Sample size of the group", "Whole number > 0"),
+        "Sx1" | "Sx2" => parameter("Sample standard deviation of the group", "Number > 0"),
+        "Successes, x" | "Successes, x1" | "Successes, x2" => parameter(
+            "Number of successes in the sample",
+            "Whole number, 0 ≤ x ≤ n",
+        ),
+        "µ0" => parameter("Mean under the null hypothesis", "Any number"),
+        "σ0" => parameter("Standard deviation under the null hypothesis", "Number > 0"),
+        "P0" => parameter(
+            "Proportion under the null hypothesis",
+            "Number between 0 and 1",
+        ),
+        "Alternate Hyp" => parameter("Direction of the alternative hypothesis", "≠, < or >"),
+        "Pooled" => parameter("Whether to pool the variances", "yes or no"),
+        "Compare" => parameter(
+            "What differs between the groups, proportions are of lists of 0s and 1s",
+            "Means, Medians or Proportions",
+        ),
+        "Group 1 List" | "Group 2 List" => {
+            parameter("Values of the group", "Comma separated numbers")
+        }
+        "Before List" => parameter("Values measured first", "Comma separated numbers"),
+        "After List" => parameter(
+            "Values measured second, paired with the before list in order",
+            "Comma separated numbers, as many as before",
+        ),
+        "Median, M0" => parameter("Median under the null hypothesis", "Any number"),
+        "Group Lists" => parameter(
+            "Values of every group, the exact test is used for up to 100000 arrangements",
+            "Comma separated numbers, groups separated by ;",
+        ),
+        "Normal µ" => parameter(
+            "Mean of the normal to compare to, blank to fit it from the data",
+            "Any number, or blank with σ",
+        ),
+        "Normal σ" => parameter(
+            "Standard deviation of the normal to compare to, blank to fit it from the data",
+            "Greater than 0, or blank with µ",
+        ),
+        "Data Matrix" => parameter(
+            "One row per subject and one column per treatment",
+            "Comma separated numbers, rows separated by ;",
+        ),
+        "Permutations, R" => parameter(
+            "Random arrangements to draw, every one is used instead when there are at most R",
+            "Whole number from 100 to 100000",
+        ),
+        "Observed List" => parameter("Observed counts", "Comma separated numbers"),
+        "Expected List" => parameter("Expected counts", "Comma separated numbers > 0"),
+        "Observed Matrix" => parameter("Table of observed counts", "Rows separated by ;"),
+        "Number of Groups" => parameter("How many groups are compared", "Whole number ≥ 2"),
+        "Group - {n}" => parameter(
+            "Sample size of every group",
+            "Comma separated whole numbers",
+        ),
+        "Group - {x̄}" => parameter("Sample mean of every group", "Comma separated numbers"),
+        "Group - Sx" => parameter(
+            "Standard deviation of every group",
+            "Comma separated numbers > 0",
+        ),
+        "X List" => parameter("Explanatory values", "Comma separated numbers"),
+        "Y List" => parameter("Response values, one per x", "Comma separated numbers"),
+        _ => return None,
+    };
+    Some(parameter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formula::TABS;
+
+    #[test]
+    fn every_formula_and_input_has_a_reference() {
+        for (_, formulas) in TABS.iter() {
+            for formula in formulas.iter() {
+                let card = retrieve_card(formula);
+                assert!(
+                    card.reference.is_some(),
+                    "{} has no reference card",
+                    formula
+                );
+                for (label, parameter) in card.parameters {
+                    assert!(
+                        parameter.is_some(),
+                        "{}'s input {} has no reference",
+                        formula,
+                        label
+                    );
+                }
+            }
+        }
+    }
+}
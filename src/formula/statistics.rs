@@ -0,0 +1,117 @@
+use anyhow::{anyhow, Result};
+
+/// Why a list holding nan or inf is refused, no statistic can be computed from it
+pub const FINITE_MESSAGE: &str = "Ensure every value in the lists is a finite number";
+
+/// Reads comma separated numbers, like a List input
+pub fn parse_list(list: &str) -> Result<Vec<f64>> {
+    list.split(',')
+        .map(|x| match x.trim().parse::<f64>() {
+            Ok(x) if x.is_finite() => Ok(x),
+            Ok(_) => Err(anyhow!(FINITE_MESSAGE)),
+            Err(_) => Err(anyhow!(
+                "Not all inputs are numbers. Please enter them again."
+            )),
+        })
+        .collect()
+}
+
+/// Reads several lists separated by semicolons, like a Lists or Matrix input
+pub fn parse_lists(lists: &str) -> Result<Vec<Vec<f64>>> {
+    lists.split(';').map(parse_list).collect()
+}
+
+/// Value a fraction `p` of the way through sorted values, interpolating between neighbours
+pub fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let position = p.clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+    let below = position.floor() as usize;
+    let above = position.ceil() as usize;
+    sorted[below] + (sorted[above] - sorted[below]) * (position - below as f64)
+}
+
+pub fn mean(list: &[f64]) -> f64 {
+    list.iter().sum::<f64>() / list.len() as f64
+}
+
+pub fn median(list: &[f64]) -> f64 {
+    let mut sorted = list.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    percentile(&sorted, 0.5)
+}
+
+/// Sample variance, dividing by n - 1
+pub fn variance(list: &[f64]) -> f64 {
+    let mean = mean(list);
+    list.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (list.len() - 1) as f64
+}
+
+pub fn std_dev(list: &[f64]) -> f64 {
+    variance(list).sqrt()
+}
+
+/// Pearson's r, NaN when either list is constant
+pub fn correlation(x: &[f64], y: &[f64]) -> f64 {
+    let (x_mean, y_mean) = (mean(x), mean(y));
+    let (mut xy, mut xx, mut yy) = (0.0, 0.0, 0.0);
+    for (a, b) in x.iter().zip(y) {
+        xy += (a - x_mean) * (b - y_mean);
+        xx += (a - x_mean).powi(2);
+        yy += (b - y_mean).powi(2);
+    }
+    xy / (xx * yy).sqrt()
+}
+
+/// Ranks from 1 up, tied values sharing the average of the ranks they span
+pub fn ranks(list: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..list.len()).collect();
+    order.sort_by(|&a, &b| list[a].total_cmp(&list[b]));
+
+    let mut ranks = vec![0.0; list.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start;
+        while end + 1 < order.len() && list[order[end + 1]] == list[order[start]] {
+            end += 1;
+        }
+        for &i in &order[start..=end] {
+            ranks[i] = (start + end) as f64 / 2.0 + 1.0;
+        }
+        start = end + 1;
+    }
+    ranks
+}
+
+/// Σ (t³ - t) over every run of t tied values, which shrinks the variance of rank statistics
+pub fn ties(list: &[f64]) -> f64 {
+    let mut sorted = list.to_vec();
+    sorted.sort_by(f64::total_cmp);
+
+    sorted
+        .chunk_by(|a, b| a == b)
+        .map(|run| (run.len() as f64).powi(3) - run.len() as f64)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_refuse_values_that_arent_finite() {
+        assert_eq!(parse_list(" 1, 2.5 ,-3").unwrap(), vec![1.0, 2.5, -3.0]);
+        for list in ["1, 2, nan", "inf, 1", "1, -inf"] {
+            assert_eq!(parse_list(list).unwrap_err().to_string(), FINITE_MESSAGE);
+        }
+        let message = parse_list("1, two").unwrap_err().to_string();
+        assert!(message.starts_with("Not all inputs are numbers"));
+        assert!(parse_lists("1, 2; 3, NaN").is_err());
+    }
+
+    #[test]
+    fn ranks_never_panic() {
+        assert_eq!(ranks(&[3.0, 1.0, 3.0]), vec![2.5, 1.0, 2.5]);
+        assert_eq!(ranks(&[f64::NAN, 1.0]).len(), 2);
+        assert_eq!(ties(&[2.0, f64::NAN, 2.0]), 6.0);
+        assert!(median(&[1.0, f64::NAN, 2.0]).is_finite());
+    }
+}
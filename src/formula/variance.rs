@@ -0,0 +1,299 @@
+use super::hypothesis::{chi_square_sf, Alternative, ALTERNATIVE_MESSAGE};
+use super::probability::{format_probability, inverse_cdf};
+use super::statistics::{mean, median, variance};
+
+use {
+    anyhow::Result,
+    statrs::{
+        distribution::{ChiSquared, FisherSnedecor, Univariate},
+        function::beta::beta_reg,
+    },
+};
+
+/// Center the absolute deviations of a Levene test are taken from
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Center {
+    /// Levene's original test
+    Mean,
+    /// The Brown-Forsythe test, robust to skewed data
+    Median,
+}
+
+/// Degrees of freedom of a sample standard deviation, or why it isn't one
+fn degrees_of_freedom(std_dev: f64, n: f64) -> Result<f64, String> {
+    if std_dev <= 0.0 {
+        Err(String::from(
+            "Ensure the standard deviation is greater than 0",
+        ))
+    } else if n < 2.0 || n.fract() != 0.0 {
+        Err(String::from("Ensure n is a whole number of at least 2"))
+    } else {
+        Ok(n - 1.0)
+    }
+}
+
+/// P(F ≥ f) for F with an F distribution, through the beta function so the far upper tail
+/// isn't lost to 1 - cdf
+fn f_sf(f: f64, df_1: f64, df_2: f64) -> f64 {
+    if f <= 0.0 {
+        1.0
+    } else {
+        beta_reg(df_2 / 2.0, df_1 / 2.0, df_2 / (df_2 + df_1 * f))
+    }
+}
+
+/// Tests a variance against σ0² with (n - 1) Sx² / σ0², which is χ2 for normal data
+pub fn get_chi_square_variance_test(
+    sigma_0: f64,
+    std_dev: f64,
+    n: f64,
+    alternative: &str,
+) -> Result<String> {
+    let alternative = match Alternative::parse(alternative) {
+        Some(alternative) => alternative,
+        None => return Ok(String::from(ALTERNATIVE_MESSAGE)),
+    };
+    if sigma_0 <= 0.0 {
+        return Ok(String::from("Ensure σ0 is greater than 0"));
+    }
+    let df = match degrees_of_freedom(std_dev, n) {
+        Ok(df) => df,
+        Err(message) => return Ok(message),
+    };
+
+    let chi_square = df * std_dev.powi(2) / sigma_0.powi(2);
+    let lower = ChiSquared::new(df)?.cdf(chi_square);
+    let p_value = alternative.p_value(lower, chi_square_sf(chi_square, df));
+
+    Ok(format!(
+        "χ2: {}\nP Value: {}\ndf: {}\nSx²: {}",
+        chi_square,
+        format_probability(p_value),
+        df,
+        std_dev.powi(2)
+    ))
+}
+
+/// Confidence interval for σ² from the χ2 distribution of (n - 1) Sx² / σ², with the
+/// interval for σ from its square roots
+pub fn get_chi_square_variance_interval(std_dev: f64, n: f64, c_level: f64) -> Result<String> {
+    if c_level <= 0.0 || c_level >= 1.0 {
+        return Ok(String::from("Ensure the C Level is between 0 and 100"));
+    }
+    let df = match degrees_of_freedom(std_dev, n) {
+        Ok(df) => df,
+        Err(message) => return Ok(message),
+    };
+
+    let chi_square = ChiSquared::new(df)?;
+    let quantile = |area| inverse_cdf(|x| chi_square.cdf(x), area);
+    let lower = df * std_dev.powi(2) / quantile((1.0 + c_level) / 2.0);
+    let upper = df * std_dev.powi(2) / quantile((1.0 - c_level) / 2.0);
+
+    Ok(format!(
+        "Lower Bound: {}\nUpper Bound: {}\nσ Lower Bound: {}\nσ Upper Bound: {}\nSx²: {}\ndf: {}",
+        lower,
+        upper,
+        lower.sqrt(),
+        upper.sqrt(),
+        std_dev.powi(2),
+        df
+    ))
+}
+
+/// Confidence interval for σ1² / σ2² from the F distribution of (Sx1² / σ1²) / (Sx2² / σ2²)
+pub fn get_2_samp_f_interval(
+    std_dev_1: f64,
+    n_1: f64,
+    std_dev_2: f64,
+    n_2: f64,
+    c_level: f64,
+) -> Result<String> {
+    if c_level <= 0.0 || c_level >= 1.0 {
+        return Ok(String::from("Ensure the C Level is between 0 and 100"));
+    }
+    let (df_1, df_2) = match (
+        degrees_of_freedom(std_dev_1, n_1),
+        degrees_of_freedom(std_dev_2, n_2),
+    ) {
+        (Ok(df_1), Ok(df_2)) => (df_1, df_2),
+        (Err(message), _) | (_, Err(message)) => return Ok(message),
+    };
+
+    let ratio = std_dev_1.powi(2) / std_dev_2.powi(2);
+    let f = FisherSnedecor::new(df_1, df_2)?;
+    let quantile = |area| inverse_cdf(|x| f.cdf(x), area);
+
+    Ok(format!(
+        "Lower Bound: {}\nUpper Bound: {}\nF: {}\nNumerator df: {}\nDenominator df: {}",
+        ratio / quantile((1.0 + c_level) / 2.0),
+        ratio / quantile((1.0 - c_level) / 2.0),
+        ratio,
+        df_1,
+        df_2
+    ))
+}
+
+/// Whether there are at least 2 groups with at least 2 values each, needed by every test of
+/// equal variances
+fn check_groups(groups: &[Vec<f64>]) -> Result<(), String> {
+    if groups.len() < 2 || groups.iter().any(|group| group.len() < 2) {
+        Err(String::from(
+            "Ensure there are at least 2 groups, each with at least 2 values",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Levene's test of equal variances, an ANOVA on the absolute deviations of each value from
+/// its group's center
+pub fn get_levene_test(groups: &[Vec<f64>], center: Center) -> Result<String> {
+    if let Err(message) = check_groups(groups) {
+        return Ok(message);
+    }
+
+    let deviations: Vec<Vec<f64>> = groups
+        .iter()
+        .map(|group| {
+            let center = match center {
+                Center::Mean => mean(group),
+                Center::Median => median(group),
+            };
+            group.iter().map(|x| (x - center).abs()).collect()
+        })
+        .collect();
+    let all: Vec<f64> = deviations.iter().flatten().copied().collect();
+    let grand_mean = mean(&all);
+
+    let between: f64 = deviations
+        .iter()
+        .map(|group| group.len() as f64 * (mean(group) - grand_mean).powi(2))
+        .sum();
+    let within: f64 = deviations
+        .iter()
+        .map(|group| (group.len() - 1) as f64 * variance(group))
+        .sum();
+    if within == 0.0 {
+        return Ok(String::from(
+            "Ensure the values of some group aren't all as far from its center",
+        ));
+    }
+
+    let df_1 = (groups.len() - 1) as f64;
+    let df_2 = (all.len() - groups.len()) as f64;
+    let f = between / df_1 / (within / df_2);
+
+    Ok(format!(
+        "F: {}\nP Value: {}\nNumerator df: {}\nDenominator df: {}",
+        f,
+        format_probability(f_sf(f, df_1, df_2)),
+        df_1,
+        df_2
+    ))
+}
+
+/// Bartlett's test of equal variances, which assumes normal data
+pub fn get_bartlett_test(groups: &[Vec<f64>]) -> Result<String> {
+    if let Err(message) = check_groups(groups) {
+        return Ok(message);
+    }
+    let variances: Vec<f64> = groups.iter().map(|group| variance(group)).collect();
+    if variances.contains(&0.0) {
+        return Ok(String::from(
+            "Ensure the values of each group aren't all the same",
+        ));
+    }
+
+    let k = groups.len() as f64;
+    let df: Vec<f64> = groups
+        .iter()
+        .map(|group| (group.len() - 1) as f64)
+        .collect();
+    let df_total: f64 = df.iter().sum();
+    let pooled = df.iter().zip(&variances).map(|(d, v)| d * v).sum::<f64>() / df_total;
+
+    let statistic = df_total * pooled.ln()
+        - df.iter()
+            .zip(&variances)
+            .map(|(d, v)| d * v.ln())
+            .sum::<f64>();
+    let correction =
+        1.0 + (df.iter().map(|d| 1.0 / d).sum::<f64>() - 1.0 / df_total) / (3.0 * (k - 1.0));
+    let chi_square = statistic / correction;
+
+    Ok(format!(
+        "χ2: {}\nP Value: {}\ndf: {}\nPooled Variance: {}",
+        chi_square,
+        format_probability(chi_square_sf(chi_square, k - 1.0)),
+        k - 1.0,
+        pooled
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::statistics::parse_lists;
+    use super::*;
+    use crate::variables::{number_field, result_fields, Value};
+
+    /// R's InsectSprays counts, as in `bartlett.test` and car's `leveneTest`
+    #[test]
+    fn equal_variance_tests_match_the_insect_sprays() -> Result<()> {
+        let sprays = parse_lists(
+            "10, 7, 20, 14, 14, 12, 10, 23, 17, 20, 14, 13; \
+             11, 17, 21, 11, 16, 14, 17, 17, 19, 21, 7, 13; \
+             0, 1, 7, 2, 3, 1, 2, 1, 3, 0, 1, 4; \
+             3, 5, 12, 6, 4, 3, 5, 5, 5, 5, 2, 4; \
+             3, 5, 3, 5, 3, 6, 1, 1, 3, 2, 6, 4; \
+             11, 9, 15, 22, 15, 16, 13, 10, 26, 26, 24, 13",
+        )?;
+
+        let output = get_bartlett_test(&sprays)?;
+        assert!(output.starts_with("χ2: 25.959825"));
+        assert!((number_field(&output, "P Value") - 9.085e-5).abs() < 1e-8);
+
+        let output = get_levene_test(&sprays, Center::Median)?;
+        assert!(output.starts_with("F: 3.821356"));
+        assert!((number_field(&output, "P Value") - 0.004223).abs() < 1e-6);
+        assert!(get_levene_test(&sprays, Center::Mean)?.starts_with("F: 6.455352"));
+
+        assert!(get_bartlett_test(&sprays[..1])?.starts_with("Ensure"));
+        Ok(())
+    }
+
+    #[test]
+    fn variance_intervals_invert_their_tests() -> Result<()> {
+        // 45 / 2 with df = 20 has the closed form e^-x Σ x^j / j! for j < 10
+        let output = get_chi_square_variance_test(2.0, 3.0, 21.0, ">")?;
+        let x: f64 = 22.5;
+        let exact = (-x).exp()
+            * (0..10)
+                .map(|j| x.powi(j) / (1..=j).product::<i32>() as f64)
+                .sum::<f64>();
+        assert!((number_field(&output, "P Value") - exact).abs() < 1e-12);
+
+        // σ0² at a bound of the interval is on the edge of being rejected
+        let bounds = |output: &str| -> Vec<f64> {
+            result_fields(output)
+                .into_iter()
+                .take(2)
+                .map(|(_, value)| match value {
+                    Value::Number(x) => x,
+                    _ => panic!("no bounds in {}", output),
+                })
+                .collect()
+        };
+        for bound in bounds(&get_chi_square_variance_interval(3.0, 20.0, 0.95)?) {
+            let output = get_chi_square_variance_test(bound.sqrt(), 3.0, 20.0, "≠")?;
+            assert!((number_field(&output, "P Value") - 0.05).abs() < 1e-6);
+        }
+
+        let ratio = bounds(&get_2_samp_f_interval(3.0, 20.0, 2.0, 25.0, 0.95)?);
+        let f = FisherSnedecor::new(19.0, 24.0)?;
+        assert!((f.cdf(2.25 / ratio[0]) - 0.975).abs() < 1e-6);
+        assert!((f.cdf(2.25 / ratio[1]) - 0.025).abs() < 1e-6);
+        assert!(get_2_samp_f_interval(3.0, 1.0, 2.0, 25.0, 0.95)?.starts_with("Ensure"));
+        Ok(())
+    }
+}
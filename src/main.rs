@@ -1,146 +1,265 @@
 use crate::{
     app::{App, InputMode},
+    config::{Action, Config, Mode},
     event::{Event, Events},
-    formula::{attempt_formula, retrieve_formula},
     ui::draw_main_layout,
+    variables::{is_valid_name, result_fields},
 };
 
 use {
     anyhow::Result,
-    std::io,
-    termion::{event::Key, input::MouseTerminal, raw::IntoRawMode, screen::AlternateScreen},
+    signal_hook::{consts::SIGTSTP, low_level::raise},
+    std::{
+        backtrace::{Backtrace, BacktraceStatus},
+        env,
+        io::{self, Stdout, Write},
+        panic::{self, AssertUnwindSafe},
+        process,
+        sync::Mutex,
+    },
+    termion::{
+        cursor,
+        event::Key,
+        input::MouseTerminal,
+        raw::{IntoRawMode, RawTerminal},
+        screen::{self, AlternateScreen},
+    },
     tui::{backend::TermionBackend, Terminal},
 };
 
+type Backend = TermionBackend<AlternateScreen<MouseTerminal<Stdout>>>;
+
+/// Filled in by the panic hook, printed once the terminal has been restored
+static CRASH_REPORT: Mutex<Option<String>> = Mutex::new(None);
+
+/// What termion's `MouseTerminal` writes to turn mouse reporting on and off
+const ENABLE_MOUSE: &str = "\x1b[?1000h\x1b[?1002h\x1b[?1015h\x1b[?1006h";
+const DISABLE_MOUSE: &str = "\x1b[?1006l\x1b[?1015l\x1b[?1002l\x1b[?1000l";
+
 mod app;
+mod config;
+mod editor;
 mod event;
 mod formula;
+mod simulation;
+mod theme;
 mod ui;
 mod util;
+mod variables;
 
 fn main() -> Result<()> {
-    let stdout = io::stdout().into_raw_mode()?;
-    let stdout = MouseTerminal::from(stdout);
+    // Load the config before touching the terminal so errors in it are printed normally
+    let mut config = Config::load()?;
+    config.apply_args(env::args().skip(1))?;
+
+    // Printing the panic message here would land on the alternate screen and be wiped,
+    // so it is kept until unwinding has dropped the terminal
+    panic::set_hook(Box::new(|info| {
+        let backtrace = Backtrace::capture();
+        let mut report = format!("{}", info);
+        if backtrace.status() == BacktraceStatus::Captured {
+            report.push_str(&format!("\n\n{}", backtrace));
+        }
+        *CRASH_REPORT.lock().unwrap() = Some(report);
+    }));
+
+    match panic::catch_unwind(AssertUnwindSafe(|| run(config))) {
+        Ok(result) => result,
+        Err(_) => {
+            let report = CRASH_REPORT.lock().unwrap().take().unwrap_or_default();
+            eprintln!("FeStats crashed, sorry about that!\n\n{}\n", report);
+            eprintln!(
+                "Please report this at https://github.com/TheCatster/FeStats/issues, along with"
+            );
+            eprintln!(
+                "the formula and inputs you were using. Run with RUST_BACKTRACE=1 for a backtrace."
+            );
+            process::exit(101);
+        }
+    }
+}
+
+fn run(config: Config) -> Result<()> {
+    // Raw mode belongs to the tty rather than a handle, so it is kept apart from the
+    // backend to be switched off while suspended. Dropped last, after the screen is restored.
+    let raw = io::stdout().into_raw_mode()?;
+    let stdout = MouseTerminal::from(io::stdout());
     let stdout = AlternateScreen::from(stdout);
     let backend = TermionBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut events = Events::new();
+    let mut events = Events::new()?;
 
-    let mut app = App::new("FeStats");
+    let mut app = App::new("FeStats", config);
+
+    let mut redraw = true;
 
     loop {
-        // Draw UI
-        terminal.draw(|f| draw_main_layout(f, &mut app))?;
+        // Draw UI, only when something happened since the last frame
+        if redraw {
+            terminal.draw(|f| draw_main_layout(f, &mut app))?;
+        }
+        redraw = true;
         // Handle input
         match events.next()? {
             Event::Input(input) => match app.input_mode {
-                InputMode::Normal => match input {
-                    Key::Char('\n') => {
-                        let current_formula = *app.current_items().current_item();
-                        let current_input_index = app.current_stored_input().len();
-                        let inputs = retrieve_formula(current_formula);
-
-                        if current_input_index == inputs.len() + 1 {
-                            app.current_stored_input().drain(..);
-                            app.current_input().1.drain(..);
-                            app.current_input().1.push(String::from(""));
-                        }
-
+                InputMode::Normal => match app.keys.action(input, Mode::Normal) {
+                    Some(Action::Edit) => {
                         app.input_mode = InputMode::Editing;
-                        events.disable_exit_key();
                     }
-                    Key::Left => {
-                        app.on_left();
+                    Some(Action::Store) => {
+                        let output = app.current_output()?;
+
+                        if !result_fields(&output).is_empty() {
+                            app.store_name.clear();
+                            app.store_field = 0;
+                            app.input_mode = InputMode::Storing;
+                        }
+                    }
+                    Some(Action::Palette) => {
+                        app.open_palette();
                     }
-                    Key::Right => {
-                        app.on_right();
+                    Some(Action::Help) => {
+                        app.input_mode = InputMode::Help;
                     }
-                    Key::Down => {
-                        app.position("next");
+                    Some(Action::Suspend) => {
+                        suspend(&mut terminal, &raw)?;
                     }
-                    Key::Up => {
-                        app.position("previous");
+                    Some(action) => {
+                        app.on_action(action)?;
                     }
-                    Key::Char('j') => {
-                        app.position("next");
+                    None => {}
+                },
+                InputMode::Editing => match app.keys.action(input, Mode::Editing) {
+                    Some(Action::Submit) => {
+                        if app.is_last_field_focused() {
+                            app.submit()?;
+                            app.input_mode = InputMode::Normal;
+                        } else {
+                            app.focus_next();
+                        }
                     }
-                    Key::Char('k') => {
-                        app.position("previous");
+                    Some(Action::NextField) => {
+                        app.focus_next();
                     }
-                    Key::Char('h') => {
-                        app.on_left();
+                    Some(Action::PreviousField) => {
+                        app.focus_previous();
                     }
-                    Key::Char('l') => {
-                        app.on_right();
+                    Some(Action::Cancel) => {
+                        app.input_mode = InputMode::Normal;
                     }
-                    Key::Char(c) => {
-                        app.on_key(c);
+                    _ => {
+                        app.edit_input(input);
                     }
-                    _ => {}
                 },
-                InputMode::Editing => match input {
-                    Key::Char('\n') => {
-                        let current_formula = *app.current_items().current_item();
-
-                        if app.current_stored_input().is_empty()
-                            || &app.current_stored_input()[0] != current_formula
-                        {
-                            app.current_stored_input().drain(..);
-                            app.current_stored_input()
-                                .push(String::from(current_formula));
+                InputMode::Help => {
+                    // Any key closes the help overlay
+                    app.input_mode = InputMode::Normal;
+                }
+                InputMode::Palette => match app.keys.action(input, Mode::Editing) {
+                    Some(Action::Submit) => {
+                        if app.palette_jump() {
+                            app.input_mode = InputMode::Editing;
+                        } else {
+                            app.input_mode = InputMode::Normal;
                         }
-
-                        let inputs = retrieve_formula(current_formula);
-                        let current_input_index = app.current_stored_input().len();
-                        let current_input = &*app.current_input_text(current_input_index - 1);
-                        let text = String::from(current_input);
-
-                        app.current_stored_input().push(text);
+                    }
+                    Some(Action::NextField) => {
+                        app.palette_next();
+                    }
+                    Some(Action::PreviousField) => {
+                        app.palette_previous();
+                    }
+                    Some(Action::Cancel) => {
                         app.input_mode = InputMode::Normal;
-                        events.enable_exit_key();
                     }
-                    Key::Char(c) => {
-                        let formula_name = app.current_items().current_item().to_owned();
-                        let inputs = retrieve_formula(formula_name);
+                    _ => {
+                        app.edit_palette(input);
+                    }
+                },
+                InputMode::Storing => match app.keys.action(input, Mode::Editing) {
+                    Some(Action::Submit) => {
+                        if is_valid_name(&app.store_name) {
+                            let output = app.current_output()?;
+                            let fields = result_fields(&output);
 
-                        let current_input_index = if !app.current_stored_input().is_empty()
-                            && app.current_stored_input().len() - 1 < inputs.len()
-                        {
-                            app.current_stored_input().len() - 1
-                        } else {
-                            0
-                        };
+                            if let Some((_, value)) = fields.get(app.store_field) {
+                                let name = app.store_name.to_owned();
+                                app.variables.set(&name, value.clone())?;
+                            }
 
-                        let current_input = app.current_input_text(current_input_index);
-                        current_input.push(c);
+                            app.input_mode = InputMode::Normal;
+                        }
                     }
-                    Key::Backspace => {
-                        let formula_name = app.current_items().current_item().to_owned();
-                        let inputs = retrieve_formula(formula_name);
-
-                        let current_input_index = if !app.current_stored_input().is_empty()
-                            && app.current_stored_input().len() - 1 < inputs.len()
-                        {
-                            app.current_stored_input().len() - 1
-                        } else {
-                            0
-                        };
+                    Some(Action::NextField) => {
+                        let output = app.current_output()?;
 
-                        let current_input = app.current_input_text(current_input_index);
-                        current_input.pop();
+                        app.store_field =
+                            (app.store_field + 1) % result_fields(&output).len().max(1);
                     }
-                    Key::Esc => {
+                    Some(Action::Cancel) => {
                         app.input_mode = InputMode::Normal;
-                        events.enable_exit_key();
                     }
-                    _ => {}
+                    _ => match input {
+                        Key::Char(c) if c.is_alphanumeric() || c == '_' => {
+                            app.store_name.push(c);
+                        }
+                        Key::Backspace => {
+                            app.store_name.pop();
+                        }
+                        _ => {}
+                    },
                 },
             },
-            Event::Tick => {}
+            Event::Mouse(mouse) => {
+                if let InputMode::Normal | InputMode::Editing = app.input_mode {
+                    app.on_mouse(mouse);
+                }
+            }
+            Event::Resize => {
+                // tui resizes its buffers on the next draw
+            }
+            Event::Tick => {
+                redraw = app.on_tick()?;
+            }
+            Event::Crashed => {
+                // Unwinding restores the terminal, the report the hook kept is printed after
+                panic::resume_unwind(Box::new("an event thread panicked"));
+            }
+        }
+        // Ticks only run while a simulation is animating on screen or resampling is going on
+        if app.needs_ticks() {
+            events.enable_ticks();
+        } else {
+            events.disable_ticks();
         }
         if app.should_quit {
             break Ok(());
         }
     }
 }
+
+/// Hands the terminal back to the shell and stops until FeStats is resumed with `fg`
+fn suspend(terminal: &mut Terminal<Backend>, raw: &RawTerminal<Stdout>) -> Result<()> {
+    let backend = terminal.backend_mut();
+    write!(
+        backend,
+        "{}{}{}",
+        DISABLE_MOUSE,
+        screen::ToMainScreen,
+        cursor::Show
+    )?;
+    backend.flush()?;
+    raw.suspend_raw_mode()?;
+
+    raise(SIGTSTP)?;
+
+    // Execution picks up here after SIGCONT
+    raw.activate_raw_mode()?;
+    let backend = terminal.backend_mut();
+    write!(backend, "{}{}", screen::ToAlternateScreen, ENABLE_MOUSE)?;
+    backend.flush()?;
+    // Forget the previous frame so everything is drawn again
+    terminal.clear()?;
+    Ok(())
+}
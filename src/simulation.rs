@@ -0,0 +1,248 @@
+use crate::formula::random::{moments, parse_seed, quantile, Quantile};
+
+use {
+    rand::{distributions::Open01, rngs::StdRng, Rng, SeedableRng},
+    std::f64::consts::PI,
+};
+
+/// Name of the formula the simulation runs under
+pub const SIMULATION: &str = "Sampling Distribution";
+
+/// Sample statistics collected before the simulation stops
+pub const MAX_SAMPLES: usize = 5000;
+
+/// Values drawn per tick at most, so a tick stays short however big the samples are
+const DRAWS_PER_TICK: u64 = 10_000;
+
+const MAX_SAMPLE_SIZE: u64 = 1000;
+
+const BINS: usize = 30;
+
+/// Repeatedly draws samples of size n and collects their means, to show the sampling
+/// distribution taking shape next to the normal curve the central limit theorem predicts
+pub struct Simulation {
+    /// The distribution and its parameters, like `Gamma(2, 3)`
+    pub name: String,
+    pub sample_size: u64,
+    /// x̄, or p̂ for a Bernoulli where the mean of a sample is a proportion
+    pub statistic: &'static str,
+    /// Mean and standard error of the statistic according to the CLT
+    pub normal: Option<(f64, f64)>,
+    pub seed: u64,
+    pub statistics: Vec<f64>,
+    quantile: Quantile,
+    rng: StdRng,
+}
+
+impl Simulation {
+    /// Reads the Sampling Distribution inputs, or explains what is wrong with them
+    pub fn from_inputs(inputs: &[String]) -> Result<Simulation, String> {
+        let parameters = inputs[1]
+            .split(',')
+            .map(|x| x.trim().parse::<f64>())
+            .collect::<Result<Vec<f64>, _>>()
+            .map_err(|_| String::from("Not all inputs are numbers. Please enter them again."))?;
+
+        let quantile = quantile(&inputs[0], &parameters).ok_or_else(|| {
+            String::from(
+                "Ensure the distribution is one listed for the Random Sample formula, with its \
+                parameters in order",
+            )
+        })?;
+
+        let sample_size = match inputs[2].trim().parse::<u64>() {
+            Ok(n) if (1..=MAX_SAMPLE_SIZE).contains(&n) => n,
+            _ => {
+                return Err(String::from(
+                    "Ensure the size of each sample is a whole number from 1 to 1000",
+                ))
+            }
+        };
+
+        let seed = match parse_seed(&inputs[3]) {
+            Some(seed) => seed.unwrap_or_else(|| rand::thread_rng().gen_range(0..1_000_000)),
+            None => return Err(String::from("Ensure the seed is a whole number")),
+        };
+
+        let distribution = inputs[0].trim();
+        let normal = moments(distribution, &parameters)
+            .map(|(mean, variance)| (mean, (variance / sample_size as f64).sqrt()));
+
+        Ok(Simulation {
+            name: format!(
+                "{}({})",
+                distribution,
+                parameters
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            sample_size,
+            statistic: if distribution.eq_ignore_ascii_case("bernoulli") {
+                "p̂"
+            } else {
+                "x̄"
+            },
+            normal,
+            seed,
+            statistics: Vec::new(),
+            quantile,
+            rng: StdRng::seed_from_u64(seed),
+        })
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.statistics.len() >= MAX_SAMPLES
+    }
+
+    /// Draws the next batch of samples, only a few at first so the histogram can be seen
+    /// filling in, then more and more as it settles
+    pub fn step(&mut self) {
+        let batch = (self.statistics.len() / 4)
+            .max(5)
+            .min((DRAWS_PER_TICK / self.sample_size).max(1) as usize)
+            .min(MAX_SAMPLES - self.statistics.len());
+
+        for _ in 0..batch {
+            let total: f64 = (0..self.sample_size)
+                .map(|_| (self.quantile)(self.rng.sample(Open01)))
+                .sum();
+            self.statistics.push(total / self.sample_size as f64);
+        }
+    }
+
+    /// Range of the x axis. With the CLT's prediction it stays put at four standard errors
+    /// either side, otherwise it follows the middle 98% of the statistics so far.
+    pub fn bounds(&self) -> (f64, f64) {
+        match self.normal {
+            Some((mean, std_error)) if std_error > 0.0 => {
+                (mean - 4.0 * std_error, mean + 4.0 * std_error)
+            }
+            _ => {
+                let mut sorted = self.statistics.clone();
+                sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let at = |fraction: f64| sorted[(fraction * (sorted.len() - 1) as f64) as usize];
+
+                match sorted.len() {
+                    0 => (-1.0, 1.0),
+                    _ if at(0.01) == at(0.99) => (at(0.01) - 1.0, at(0.99) + 1.0),
+                    _ => (at(0.01), at(0.99)),
+                }
+            }
+        }
+    }
+
+    /// Outline of the histogram scaled as a density, so it lines up with the normal curve
+    pub fn histogram(&self) -> Vec<(f64, f64)> {
+        let (lower, upper) = self.bounds();
+        let width = (upper - lower) / BINS as f64;
+        let mut counts = [0_usize; BINS];
+
+        for &statistic in &self.statistics {
+            let bin = ((statistic - lower) / width).floor();
+            if bin >= 0.0 && bin < BINS as f64 {
+                counts[bin as usize] += 1;
+            }
+        }
+
+        let scale = 1.0 / (self.statistics.len().max(1) as f64 * width);
+        let mut outline = vec![(lower, 0.0)];
+        for (bin, &count) in counts.iter().enumerate() {
+            let height = count as f64 * scale;
+            outline.push((lower + bin as f64 * width, height));
+            outline.push((lower + (bin + 1) as f64 * width, height));
+        }
+        outline.push((upper, 0.0));
+        outline
+    }
+
+    /// The normal density the CLT predicts, if it applies
+    pub fn normal_curve(&self) -> Vec<(f64, f64)> {
+        let (mean, std_error) = match self.normal {
+            Some((mean, std_error)) if std_error > 0.0 => (mean, std_error),
+            _ => return Vec::new(),
+        };
+        let (lower, upper) = self.bounds();
+
+        (0..=200)
+            .map(|i| {
+                let x = lower + (upper - lower) * i as f64 / 200.0;
+                let z = (x - mean) / std_error;
+                (x, (-z * z / 2.0).exp() / (std_error * (2.0 * PI).sqrt()))
+            })
+            .collect()
+    }
+
+    pub fn summary(&self) -> String {
+        let count = self.statistics.len().max(1) as f64;
+        let mean = self.statistics.iter().sum::<f64>() / count;
+        let std_dev = (self
+            .statistics
+            .iter()
+            .map(|x| (x - mean).powi(2))
+            .sum::<f64>()
+            / (count - 1.0).max(1.0))
+        .sqrt();
+
+        let prediction = match self.normal {
+            Some((mean, std_error)) => format!("CLT: N({:.4}, {:.4})", mean, std_error),
+            None => String::from("CLT: doesn't apply, the variance is infinite"),
+        };
+
+        format!(
+            "{} samples of {} from {}\nMean of {}: {:.4}  SD of {}: {:.4}\n{}\nSeed: {}",
+            self.statistics.len(),
+            self.sample_size,
+            self.name,
+            self.statistic,
+            mean,
+            self.statistic,
+            std_dev,
+            prediction,
+            self.seed
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formula::random::moments;
+
+    #[test]
+    fn sample_means_follow_the_central_limit_theorem() {
+        let inputs = ["Exponential", "1", "30", "8"].map(String::from);
+        let mut simulation = Simulation::from_inputs(&inputs).unwrap();
+        while !simulation.is_finished() {
+            simulation.step();
+        }
+
+        let means = &simulation.statistics;
+        let mean = means.iter().sum::<f64>() / means.len() as f64;
+        let std_dev = (means.iter().map(|x| (x - mean).powi(2)).sum::<f64>()
+            / (means.len() - 1) as f64)
+            .sqrt();
+        let (clt_mean, std_error) = simulation.normal.unwrap();
+
+        assert!(
+            (clt_mean - 1.0).abs() < 1e-12 && (std_error - 30_f64.sqrt().recip()).abs() < 1e-12
+        );
+        assert!((mean - clt_mean).abs() < 0.01);
+        assert!((std_dev - std_error).abs() < 0.01);
+    }
+
+    #[test]
+    fn heavy_tails_have_no_normal_approximation() {
+        assert_eq!(moments("Cauchy", &[0.0, 1.0]), None);
+        assert_eq!(moments("t", &[2.0]), None);
+        assert_eq!(moments("t", &[4.0]), Some((0.0, 2.0)));
+
+        let inputs = ["Cauchy", "0, 1", "10", ""].map(String::from);
+        let simulation = Simulation::from_inputs(&inputs).unwrap();
+        assert!(simulation.normal_curve().is_empty());
+
+        let inputs = ["Normal", "0, 1", "0", ""].map(String::from);
+        assert!(Simulation::from_inputs(&inputs).is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use tui::style::{Color, Modifier, Style};
+
+pub const THEMES: [&str; 4] = ["dark", "light", "high-contrast", "monochrome"];
+
+/// Every style the interface is drawn with
+#[derive(Clone, Copy)]
+pub struct Theme {
+    /// Plain text, borders and unselected items
+    pub text: Style,
+    /// First letter of each tab title, which is its hotkey
+    pub tab_hotkey: Style,
+    pub tab_title: Style,
+    pub tab_selected: Style,
+    pub list_selected: Style,
+    /// The input or prompt currently being typed into
+    pub focused: Style,
+    /// Key names in the help overlay
+    pub key: Style,
+    pub heading: Style,
+    /// Secondary text, such as the tab name next to a palette match
+    pub muted: Style,
+}
+
+impl Theme {
+    pub fn named(name: &str) -> Option<Theme> {
+        match name {
+            "dark" => Some(Theme::dark()),
+            "light" => Some(Theme::light()),
+            "high-contrast" => Some(Theme::high_contrast()),
+            "monochrome" => Some(Theme::monochrome()),
+            _ => None,
+        }
+    }
+
+    pub fn dark() -> Theme {
+        Theme {
+            text: Style::default(),
+            tab_hotkey: Style::default().fg(Color::Yellow),
+            tab_title: Style::default().fg(Color::Green),
+            tab_selected: Style::default()
+                .add_modifier(Modifier::BOLD)
+                .bg(Color::DarkGray),
+            list_selected: Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+            focused: Style::default().fg(Color::Yellow),
+            key: Style::default().fg(Color::Yellow),
+            heading: Style::default().add_modifier(Modifier::BOLD),
+            muted: Style::default().fg(Color::DarkGray),
+        }
+    }
+
+    pub fn light() -> Theme {
+        Theme {
+            text: Style::default(),
+            tab_hotkey: Style::default().fg(Color::Red),
+            tab_title: Style::default().fg(Color::Blue),
+            tab_selected: Style::default()
+                .add_modifier(Modifier::BOLD)
+                .bg(Color::Gray),
+            list_selected: Style::default()
+                .bg(Color::Gray)
+                .add_modifier(Modifier::BOLD),
+            focused: Style::default().fg(Color::Blue),
+            key: Style::default().fg(Color::Magenta),
+            heading: Style::default().add_modifier(Modifier::BOLD),
+            muted: Style::default().fg(Color::Gray),
+        }
+    }
+
+    pub fn high_contrast() -> Theme {
+        Theme {
+            text: Style::default().fg(Color::White).bg(Color::Black),
+            tab_hotkey: Style::default()
+                .fg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            tab_title: Style::default().fg(Color::White),
+            tab_selected: Style::default()
+                .fg(Color::Black)
+                .bg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD),
+            list_selected: Style::default()
+                .fg(Color::Black)
+                .bg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD),
+            focused: Style::default()
+                .fg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD),
+            key: Style::default()
+                .fg(Color::LightCyan)
+                .add_modifier(Modifier::BOLD),
+            heading: Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            muted: Style::default().fg(Color::White),
+        }
+    }
+
+    /// Uses no colors at all, only bold, underline and reverse video
+    pub fn monochrome() -> Theme {
+        Theme {
+            text: Style::default(),
+            tab_hotkey: Style::default().add_modifier(Modifier::UNDERLINED),
+            tab_title: Style::default(),
+            tab_selected: Style::default().add_modifier(Modifier::REVERSED),
+            list_selected: Style::default().add_modifier(Modifier::REVERSED),
+            focused: Style::default().add_modifier(Modifier::BOLD),
+            key: Style::default().add_modifier(Modifier::BOLD),
+            heading: Style::default().add_modifier(Modifier::UNDERLINED),
+            muted: Style::default(),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme::dark()
+    }
+}
@@ -1,17 +1,28 @@
 use crate::{
     app::{App, InputMode},
-    formula::{attempt_formula, retrieve_formula},
+    config::{key_name, Action, Mode},
+    editor::EDITING_KEYS,
+    formula::{
+        bootstrap::BOOTSTRAP,
+        reference::{retrieve_card, Card},
+        retrieve_formula,
+    },
+    simulation::{Simulation, SIMULATION},
+    theme::Theme,
+    variables::{result_fields, Value},
 };
 
 use {
     anyhow::Result,
-    std::convert::TryInto,
     tui::{
         backend::Backend,
         layout::{Alignment, Constraint, Direction, Layout, Rect},
-        style::{Color, Modifier, Style},
+        symbols::Marker,
         text::{Span, Spans, Text},
-        widgets::{Block, Borders, List, ListItem, Paragraph, Tabs, Wrap},
+        widgets::{
+            Axis, Block, Borders, Chart, Clear, Dataset, Gauge, GraphType, List, ListItem,
+            ListState, Paragraph, Tabs, Wrap,
+        },
         Frame,
     },
     unicode_width::UnicodeWidthStr,
@@ -21,6 +32,14 @@ pub fn draw_main_layout<B>(f: &mut Frame<B>, app: &mut App)
 where
     B: Backend,
 {
+    // Paint the theme's background under everything
+    f.render_widget(Block::default().style(app.theme.text), f.size());
+
+    if f.size().width < MIN_WIDTH || f.size().height < MIN_HEIGHT {
+        draw_too_small(f, app);
+        return;
+    }
+
     let chunks = Layout::default()
         .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
         .split(f.size());
@@ -31,33 +50,234 @@ where
         .map(|t| {
             let (first, rest) = t.split_at(1);
             Spans::from(vec![
-                Span::styled(first, Style::default().fg(Color::Yellow)),
-                Span::styled(rest, Style::default().fg(Color::Green)),
+                Span::styled(first, app.theme.tab_hotkey),
+                Span::styled(rest, app.theme.tab_title),
             ])
         })
         .collect();
+    let title = if app.status.is_empty() {
+        String::from(app.title)
+    } else {
+        format!("{} - {}", app.title, app.status)
+    };
     let tabs = Tabs::new(titles)
-        .block(Block::default().borders(Borders::ALL).title(app.title))
+        .block(Block::default().borders(Borders::ALL).title(title))
         .select(app.tabs.index)
-        .style(Style::default())
-        .highlight_style(
-            Style::default()
-                .add_modifier(Modifier::BOLD)
-                .bg(Color::Black),
-        );
+        .style(app.theme.text)
+        .highlight_style(app.theme.tab_selected);
     f.render_widget(tabs, chunks[0]);
+    app.areas.tabs = chunks[0];
 
     draw_body(f, app, chunks[1]);
+
+    match app.input_mode {
+        InputMode::Palette => draw_palette(f, app),
+        InputMode::Help => draw_help(f, app),
+        _ => {}
+    }
 }
 
-fn draw_body<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
+fn draw_help<B>(f: &mut Frame<B>, app: &mut App)
 where
     B: Backend,
 {
+    let area = centered_rect(90, 90, f.size());
+    f.render_widget(Clear, area);
+
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .margin(2)
-        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)].as_ref())
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .split(area);
+
+    let heading = app.theme.heading;
+    let mut keys = vec![Spans::from(Span::styled("Browsing", heading))];
+    for mode in [Mode::Normal, Mode::Editing].iter() {
+        if *mode == Mode::Editing {
+            keys.push(Spans::from(""));
+            keys.push(Spans::from(Span::styled("Editing", heading)));
+        }
+        for action in Action::ALL.iter().filter(|action| action.mode() == *mode) {
+            let bound = app
+                .keys
+                .keys(*action)
+                .iter()
+                .map(|key| key_name(*key))
+                .collect::<Vec<String>>()
+                .join(", ");
+            keys.push(Spans::from(vec![
+                Span::styled(format!("{:<18}", bound), app.theme.key),
+                Span::raw(action.description()),
+            ]));
+        }
+    }
+    for (bound, description) in EDITING_KEYS.iter() {
+        let bound = bound
+            .iter()
+            .map(|key| key_name(*key))
+            .collect::<Vec<String>>()
+            .join(", ");
+        keys.push(Spans::from(vec![
+            Span::styled(format!("{:<18}", bound), app.theme.key),
+            Span::raw(*description),
+        ]));
+    }
+    let keys = Paragraph::new(keys)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Keys (any key closes)"),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(keys, chunks[0]);
+
+    let formula_name = *app.current_items().current_item();
+    let Card {
+        reference,
+        parameters,
+    } = retrieve_card(formula_name);
+    let mut card = match reference {
+        Some(reference) => vec![
+            Spans::from(reference.description),
+            Spans::from(""),
+            Spans::from(Span::styled("Formula", heading)),
+            Spans::from(reference.formula),
+            Spans::from(""),
+            Spans::from(Span::styled("Example", heading)),
+            Spans::from(reference.example),
+            Spans::from(""),
+        ],
+        None => vec![],
+    };
+    card.push(Spans::from(Span::styled("Parameters", heading)));
+    for (label, parameter) in parameters {
+        let mut line = vec![Span::styled(label, app.theme.key)];
+        if let Some(parameter) = parameter {
+            line.push(Span::raw(format!(
+                ": {} ({})",
+                parameter.description, parameter.range
+            )));
+        }
+        card.push(Spans::from(line));
+    }
+    let card = Paragraph::new(card)
+        .block(Block::default().borders(Borders::ALL).title(formula_name))
+        .wrap(Wrap { trim: false });
+    f.render_widget(card, chunks[1]);
+}
+
+/// A rectangle of the given percentage size, centered inside `area`
+fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Percentage((100 - percent_y) / 2),
+                Constraint::Percentage(percent_y),
+                Constraint::Percentage((100 - percent_y) / 2),
+            ]
+            .as_ref(),
+        )
+        .split(area);
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Percentage((100 - percent_x) / 2),
+                Constraint::Percentage(percent_x),
+                Constraint::Percentage((100 - percent_x) / 2),
+            ]
+            .as_ref(),
+        )
+        .split(vertical[1])[1]
+}
+
+fn draw_palette<B>(f: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let area = centered_rect(60, 60, f.size());
+    f.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
+        .split(area);
+
+    let query = Paragraph::new(app.palette.text())
+        .style(app.theme.focused)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Search formulas"),
+        );
+    f.render_widget(query, chunks[0]);
+
+    let items: Vec<ListItem> = app
+        .palette_matches()
+        .iter()
+        .map(|(tab, _, name)| {
+            ListItem::new(Spans::from(vec![
+                Span::raw(*name),
+                Span::styled(format!("  ({})", app.tabs.titles[*tab]), app.theme.muted),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL))
+        .highlight_style(app.theme.list_selected)
+        .highlight_symbol(">> ");
+    let mut state = ListState::default();
+    state.select(Some(app.palette_selected));
+    f.render_stateful_widget(list, chunks[1], &mut state);
+
+    f.set_cursor(
+        chunks[0].x + app.palette.cursor_width() as u16 + 1,
+        chunks[0].y + 1,
+    );
+}
+
+/// Below this width the list, inputs and output are stacked instead of side by side
+const NARROW_WIDTH: u16 = 80;
+/// Smallest terminal FeStats can draw a usable interface in
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 15;
+/// Every input is a one line box with a border above and below
+const INPUT_HEIGHT: u16 = 3;
+
+fn draw_too_small<B>(f: &mut Frame<B>, app: &App)
+where
+    B: Backend,
+{
+    let size = f.size();
+    let message = Paragraph::new(format!(
+        "Terminal too small\n\n{}x{} needed, {}x{} available",
+        MIN_WIDTH, MIN_HEIGHT, size.width, size.height
+    ))
+    .style(app.theme.text)
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+    f.render_widget(message, size);
+}
+
+fn draw_body<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
+where
+    B: Backend,
+{
+    let constraints = if area.width < NARROW_WIDTH {
+        // Enough of the list to see the selection and its neighbours
+        let list_height = (app.current_items().items.len() as u16 + 2).min(area.height / 4 + 2);
+        [Constraint::Length(list_height), Constraint::Min(0)]
+    } else {
+        [Constraint::Percentage(30), Constraint::Percentage(70)]
+    };
+    let chunks = Layout::default()
+        .direction(if area.width < NARROW_WIDTH {
+            Direction::Vertical
+        } else {
+            Direction::Horizontal
+        })
+        .margin(1)
+        .constraints(constraints.as_ref())
         .split(area);
     let block = Block::default()
         .borders(Borders::ALL)
@@ -73,30 +293,105 @@ where
     B: Backend,
 {
     let formula_name = app.current_items().current_item().to_owned();
-    let current_inputs = app.current_stored_input_ref();
-    let stored_input = app.current_stored_input().to_owned();
     let inputs = retrieve_formula(formula_name);
-    let current_items = app.current_items().current_item().to_owned();
-    let outputs = &attempt_formula(
-        app,
-        current_items,
-        &stored_input,
-    )?;
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .margin(2)
-        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)].as_ref())
-        .split(area);
+    let outputs = &app.current_output()?;
+
+    let inner = Block::default().borders(Borders::ALL).inner(area);
+    let chunks = if area.width < NARROW_WIDTH {
+        // Inputs get at most half the height and scroll, the output gets the rest
+        let input_height = (inputs.len() as u16 * INPUT_HEIGHT).min(inner.height / 2);
+        Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([Constraint::Length(input_height), Constraint::Min(0)].as_ref())
+            .split(area)
+    } else {
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .margin(1)
+            .constraints([Constraint::Percentage(35), Constraint::Percentage(65)].as_ref())
+            .split(area)
+    };
     let block = Block::default()
         .borders(Borders::ALL)
         .title(*app.current_items().current_item());
     f.render_widget(block, area);
 
     draw_inputs(f, app, chunks[0], inputs);
-    draw_formula(f, chunks[1], &formula_name, outputs);
+    let scroll = app.current_state().scroll;
+    match app.input_mode {
+        InputMode::Storing => {
+            let output_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
+                .split(chunks[1]);
+            app.areas.output = output_chunks[0];
+            draw_formula(
+                f,
+                output_chunks[0],
+                app.theme,
+                formula_name,
+                outputs,
+                scroll,
+            );
+            draw_store_prompt(f, app, output_chunks[1], outputs);
+        }
+        _ => {
+            app.areas.output = chunks[1];
+            match &app.simulation {
+                Some(simulation) if formula_name == SIMULATION => {
+                    draw_simulation(f, chunks[1], app.theme, simulation)
+                }
+                _ => draw_formula(f, chunks[1], app.theme, formula_name, outputs, scroll),
+            }
+            if let Some((_, bootstrap)) = &app.bootstrap {
+                if formula_name == BOOTSTRAP {
+                    let gauge_area = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
+                        .split(chunks[1])[1];
+                    let gauge = Gauge::default()
+                        .block(Block::default().borders(Borders::ALL).title("Resampling"))
+                        .gauge_style(app.theme.focused)
+                        .ratio(bootstrap.progress())
+                        .label(format!(
+                            "{} of {}",
+                            bootstrap.completed(),
+                            bootstrap.resamples
+                        ));
+                    f.render_widget(Clear, gauge_area);
+                    f.render_widget(gauge, gauge_area);
+                }
+            }
+        }
+    }
     Ok(())
 }
 
+fn draw_store_prompt<B>(f: &mut Frame<B>, app: &mut App, area: Rect, outputs: &str)
+where
+    B: Backend,
+{
+    let fields = result_fields(outputs);
+    let title = match fields.get(app.store_field) {
+        Some((label, Value::List(list))) => {
+            format!(
+                "Store {} ({} numbers) as [Tab: next field]",
+                label,
+                list.len()
+            )
+        }
+        Some((label, value)) => format!("Store {} ({}) as [Tab: next field]", label, value),
+        None => String::from("Store as"),
+    };
+    let prompt = Paragraph::new(app.store_name.as_str())
+        .style(app.theme.focused)
+        .block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(prompt, area);
+
+    f.set_cursor(area.x + app.store_name.width() as u16 + 1, area.y + 1)
+}
+
 fn draw_list<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
 where
     B: Backend,
@@ -106,27 +401,39 @@ where
         .constraints([Constraint::Percentage(100)].as_ref())
         .split(area);
 
-    let items: Vec<ListItem> = app
-        .current_items()
+    // Only the visible window of the list is handed to `List`, so that the scroll offset is
+    // known when mapping mouse clicks back to items
+    let theme = app.theme;
+    let list = app.current_items();
+    list.scroll_to_selected(chunks[0].height.saturating_sub(2) as usize);
+    let offset = list.offset;
+    let mut state = ListState::default();
+    state.select(Some(list.current_item_index() - offset));
+
+    let items: Vec<ListItem> = list
         .items
         .iter()
-        .map(|i| ListItem::new(Spans::from(*i)).style(Style::default()))
+        .skip(offset)
+        .map(|i| ListItem::new(Spans::from(*i)).style(theme.text))
         .collect();
 
     let items = List::new(items)
         .block(Block::default().borders(Borders::ALL).title("List"))
-        .highlight_style(
-            Style::default()
-                .bg(Color::Black)
-                .add_modifier(Modifier::BOLD),
-        )
+        .highlight_style(theme.list_selected)
         .highlight_symbol(">> ");
 
-    f.render_stateful_widget(items, chunks[0], &mut app.current_items().state);
+    f.render_stateful_widget(items, chunks[0], &mut state);
+    app.areas.list = chunks[0];
 }
 
-fn draw_formula<B>(f: &mut Frame<B>, area: Rect, formula: &str, outputs: &str)
-where
+fn draw_formula<B>(
+    f: &mut Frame<B>,
+    area: Rect,
+    theme: Theme,
+    formula: &str,
+    outputs: &str,
+    scroll: u16,
+) where
     B: Backend,
 {
     let chunks = Layout::default()
@@ -138,75 +445,160 @@ where
     let mut text = Text::from(outputs);
     let formula_output = Paragraph::new(text)
         .block(Block::default().borders(Borders::ALL).title("Output"))
-        .style(Style::default())
+        .style(theme.text)
         .alignment(Alignment::Center)
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap { trim: true })
+        .scroll((scroll, 0));
     f.render_widget(formula_output, chunks[0]);
 }
 
+/// The histogram of the simulated statistics so far, over the CLT's normal curve
+fn draw_simulation<B>(f: &mut Frame<B>, area: Rect, theme: Theme, simulation: &Simulation)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(6)].as_ref())
+        .split(area);
+
+    let histogram = simulation.histogram();
+    let curve = simulation.normal_curve();
+    let (lower, upper) = simulation.bounds();
+    let top = histogram
+        .iter()
+        .chain(&curve)
+        .map(|&(_, y)| y)
+        .fold(f64::MIN_POSITIVE, f64::max)
+        * 1.1;
+
+    let mut datasets = vec![Dataset::default()
+        .name(format!("Sample {}", simulation.statistic))
+        .marker(Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(theme.focused)
+        .data(&histogram)];
+    if !curve.is_empty() {
+        datasets.push(
+            Dataset::default()
+                .name("Normal")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(theme.key)
+                .data(&curve),
+        );
+    }
+
+    let label = |x: f64| Span::styled(format!("{:.3}", x), theme.muted);
+    let chart = Chart::new(datasets)
+        .block(Block::default().borders(Borders::ALL).title("Output"))
+        .style(theme.text)
+        .x_axis(
+            Axis::default()
+                .title(simulation.statistic)
+                .style(theme.text)
+                .bounds([lower, upper])
+                .labels(vec![
+                    label(lower),
+                    label((lower + upper) / 2.0),
+                    label(upper),
+                ]),
+        )
+        .y_axis(
+            Axis::default()
+                .title("Density")
+                .style(theme.text)
+                .bounds([0.0, top])
+                .labels(vec![label(0.0), label(top)]),
+        );
+    f.render_widget(chart, chunks[0]);
+
+    let summary = Paragraph::new(simulation.summary())
+        .block(Block::default().borders(Borders::ALL))
+        .style(theme.text)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    f.render_widget(summary, chunks[1]);
+}
+
 fn draw_inputs<B>(f: &mut Frame<B>, app: &mut App, area: Rect, variables: Vec<String>)
 where
     B: Backend,
 {
-    let mut constraints: Vec<Constraint> = vec![]; // Constraint::Percentage(100)
+    let current_input_index = *app.current_focus();
 
-    for x in &variables {
-        constraints.push(Constraint::Percentage(
-            (100 / &variables.len()).try_into().unwrap(),
-        ));
+    // Scroll just far enough to keep the focused input on screen
+    let visible = ((area.height / INPUT_HEIGHT) as usize).max(1);
+    let state = app.current_state();
+    if current_input_index < state.input_scroll {
+        state.input_scroll = current_input_index;
+    } else if current_input_index >= state.input_scroll + visible {
+        state.input_scroll = current_input_index + 1 - visible;
     }
+    state.input_scroll = state
+        .input_scroll
+        .min(variables.len().saturating_sub(visible));
+    let offset = state.input_scroll;
 
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints(constraints.as_ref())
-        .split(area);
+    app.areas.inputs.clear();
+    for (index, label) in variables.iter().enumerate().skip(offset).take(visible) {
+        let row = Rect {
+            y: area.y + (index - offset) as u16 * INPUT_HEIGHT,
+            height: INPUT_HEIGHT.min(area.height),
+            ..area
+        };
 
-    let current_input_index = if !app.current_stored_input().is_empty()
-        && app.current_stored_input().len() - 1 < constraints.len()
-    {
-        app.current_stored_input().len() - 1
-    } else {
-        0
-    };
+        let title = if index == offset && offset > 0 {
+            format!("{} ↑{}", label, offset)
+        } else if index + 1 == offset + visible && index + 1 < variables.len() {
+            format!("{} ↓{}", label, variables.len() - index - 1)
+        } else {
+            label.to_owned()
+        };
 
-    for y in &variables {
-        let index = &variables.iter().position(|x| x == y).unwrap();
-        let paragraph = app.current_input_text(*index).to_owned();
-        let input = Paragraph::new(paragraph)
+        // Scroll the text sideways so the cursor never leaves the box
+        let field = app.current_input_field(index);
+        let text_width = row.width.saturating_sub(3) as usize;
+        let horizontal_scroll = field.cursor_width().saturating_sub(text_width) as u16;
+        let input = Paragraph::new(field.text().to_owned())
             .style(match app.input_mode {
-                InputMode::Normal => Style::default(),
+                InputMode::Normal | InputMode::Storing | InputMode::Palette | InputMode::Help => {
+                    app.theme.text
+                }
                 InputMode::Editing => {
-                    if &current_input_index == index {
-                        Style::default().fg(Color::Yellow)
+                    if current_input_index == index {
+                        app.theme.focused
                     } else {
-                        Style::default()
+                        app.theme.text
                     }
                 }
             })
-            .wrap(Wrap { trim: true })
-            .block(Block::default().borders(Borders::ALL).title(y.as_str()));
-        f.render_widget(input, chunks[*index]);
-    }
+            .scroll((0, horizontal_scroll))
+            .block(Block::default().borders(Borders::ALL).title(title));
+        f.render_widget(input, row);
+        app.areas.inputs.push((index, row));
 
-    match app.input_mode {
-        InputMode::Normal =>
-            // Hide the cursor. `Frame` does this by default, so we don't need to do anything here
-            {}
-
-        InputMode::Editing => {
-            // Make the cursor visible and ask tui-rs to put it at the specified coordinates after rendering
-            select_next_input(f, app, chunks[current_input_index], current_input_index);
+        if let InputMode::Editing = app.input_mode {
+            if current_input_index == index {
+                // Make the cursor visible and ask tui-rs to put it at the specified coordinates after rendering
+                select_next_input(f, app, row, index, horizontal_scroll);
+            }
         }
     }
 }
 
-pub fn select_next_input<B>(f: &mut Frame<B>, app: &mut App, area: Rect, input_index: usize)
-where
+pub fn select_next_input<B>(
+    f: &mut Frame<B>,
+    app: &mut App,
+    area: Rect,
+    input_index: usize,
+    horizontal_scroll: u16,
+) where
     B: Backend,
 {
     f.set_cursor(
-        // Put cursor past the end of the input text
-        area.x + app.current_input_text(input_index).width() as u16 + 1,
+        // Put cursor at the editing position within the input text
+        area.x + app.current_input_field(input_index).cursor_width() as u16 - horizontal_scroll + 1,
         // Move one line down, from the border to the input line
         area.y + 1,
     )
@@ -1,32 +1,19 @@
-use crate::app::App;
-
 use {
-    rand::{
-        distributions::{Distribution, Uniform},
-        rngs::ThreadRng,
-    },
+    std::{env, path::PathBuf},
     tui::widgets::ListState,
+    unicode_width::UnicodeWidthStr,
 };
 
-#[derive(Clone)]
-pub struct RandomSignal {
-    distribution: Uniform<u64>,
-    rng: ThreadRng,
-}
-
-impl RandomSignal {
-    pub fn new(lower: u64, upper: u64) -> RandomSignal {
-        RandomSignal {
-            distribution: Uniform::new(lower, upper),
-            rng: rand::thread_rng(),
-        }
-    }
-}
-
-impl Iterator for RandomSignal {
-    type Item = u64;
-    fn next(&mut self) -> Option<u64> {
-        Some(self.distribution.sample(&mut self.rng))
+/// Directory FeStats keeps its session data in, following the XDG base directory spec
+pub fn data_dir() -> Option<PathBuf> {
+    match env::var_os("XDG_DATA_HOME") {
+        Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir).join("festats")),
+        _ => env::var_os("HOME").map(|home| {
+            PathBuf::from(home)
+                .join(".local")
+                .join("share")
+                .join("festats")
+        }),
     }
 }
 
@@ -58,6 +45,67 @@ impl Iterator for SinSignal {
     }
 }
 
+/// Scores how well `query` fuzzy matches `candidate`, or `None` if it doesn't match at all.
+/// Every query character has to appear in order, with consecutive runs and word starts
+/// scoring higher than scattered matches.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut score = 0;
+    let mut last_match: Option<usize> = None;
+    let mut position = 0;
+
+    for q in query {
+        let index = (position..candidate.len()).find(|&i| candidate[i] == q)?;
+
+        score += match last_match {
+            Some(last) if last + 1 == index => 8,
+            _ if index == 0 || !candidate[index - 1].is_alphanumeric() => 6,
+            Some(last) => 1 - (index - last) as i64,
+            None => 1 - index as i64,
+        };
+        last_match = Some(index);
+        position = index + 1;
+    }
+
+    // Prefer shorter candidates when the matches are otherwise equal
+    Some(score * 4 - candidate.len() as i64)
+}
+
+/// Rows `text` takes up when word wrapped to `width` columns, the way `Paragraph` wraps it
+pub fn wrapped_height(text: &str, width: u16) -> u16 {
+    let width = width.max(1) as usize;
+    let rows: usize = text
+        .lines()
+        .map(|line| {
+            let (mut rows, mut used) = (1, 0);
+            for word in line.split_whitespace() {
+                let word_width = word.width();
+                if used == 0 {
+                    used = word_width;
+                } else if used + 1 + word_width <= width {
+                    used += 1 + word_width;
+                } else {
+                    rows += 1;
+                    used = word_width;
+                }
+                // Words wider than a row are broken across several
+                if used > width {
+                    rows += (used - 1) / width;
+                    used = (used - 1) % width + 1;
+                }
+            }
+            rows
+        })
+        .sum();
+    rows.min(u16::MAX as usize) as u16
+}
+
 pub struct TabsState<'a> {
     pub titles: Vec<&'a str>,
     pub index: usize,
@@ -88,20 +136,16 @@ impl<'a> TabsState<'a> {
 pub struct StatefulList<T> {
     pub state: ListState,
     pub items: Vec<T>,
+    /// Index of the first visible item, kept here since `ListState` doesn't expose its own
+    pub offset: usize,
 }
 
 impl<T> StatefulList<T> {
-    pub fn new() -> StatefulList<T> {
-        StatefulList {
-            state: ListState::default(),
-            items: Vec::new(),
-        }
-    }
-
     pub fn with_items(items: Vec<T>) -> StatefulList<T> {
         StatefulList {
             state: ListState::default(),
             items,
+            offset: 0,
         }
     }
 
@@ -121,9 +165,7 @@ impl<T> StatefulList<T> {
         i
     }
 
-    pub fn position(&mut self, position: &str, app: &mut App) {
-        app.current_stored_input().drain(..);
-        app.current_input().0 = 0;
+    pub fn position(&mut self, position: &str) {
         let i = match position {
             "next" => match self.state.selected() {
                 Some(i) => {
@@ -151,7 +193,28 @@ impl<T> StatefulList<T> {
         self.state.select(Some(i));
     }
 
-    pub fn unselect(&mut self) {
-        self.state.select(None);
+    /// Scrolls so that the selected item is visible in a list `height` rows tall
+    pub fn scroll_to_selected(&mut self, height: usize) {
+        let selected = self.current_item_index();
+        if selected < self.offset {
+            self.offset = selected;
+        } else if height > 0 && selected >= self.offset + height {
+            self.offset = selected + 1 - height;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapped_height_counts_word_wrapped_rows() {
+        assert_eq!(wrapped_height("t: 2.5\nP Value: 0.01", 20), 2);
+        assert_eq!(wrapped_height("Mean Difference: -1.58", 10), 3);
+        assert_eq!(wrapped_height("", 10), 0);
+        assert_eq!(wrapped_height("a\n\nb", 10), 3);
+        // A word wider than the pane takes as many rows as it needs
+        assert_eq!(wrapped_height("0.123456789012345", 5), 4);
     }
 }
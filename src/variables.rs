@@ -0,0 +1,159 @@
+use crate::util::data_dir;
+
+use {
+    anyhow::{bail, Result},
+    std::{collections::BTreeMap, fmt, fs, path::PathBuf},
+};
+
+/// Name of the register that always holds the last primary result
+pub const ANS: &str = "Ans";
+
+/// A stored number, or a list of numbers such as a random sample
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Number(f64),
+    List(Vec<f64>),
+}
+
+impl Value {
+    /// Reads a number, or comma separated numbers as a list
+    pub fn parse(text: &str) -> Option<Value> {
+        if let Ok(number) = text.trim().parse::<f64>() {
+            return Some(Value::Number(number));
+        }
+
+        text.split(',')
+            .map(|entry| entry.trim().parse::<f64>().ok())
+            .collect::<Option<Vec<f64>>>()
+            .map(Value::List)
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{}", number),
+            Value::List(list) => write!(
+                f,
+                "{}",
+                list.iter()
+                    .map(|number| number.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+/// Named values that can be stored from a formula result and used in any input box
+pub struct Variables {
+    values: BTreeMap<String, Value>,
+    path: Option<PathBuf>,
+}
+
+impl Variables {
+    /// Loads the variables saved by the last session, if there are any
+    pub fn load() -> Variables {
+        let path = data_dir().map(|dir| dir.join("variables"));
+        let mut values = BTreeMap::new();
+
+        if let Some(contents) = path.as_ref().and_then(|path| fs::read_to_string(path).ok()) {
+            for line in contents.lines() {
+                if let Some((name, value)) = line.split_once('=') {
+                    if let Some(value) = Value::parse(value) {
+                        values.insert(String::from(name.trim()), value);
+                    }
+                }
+            }
+        }
+
+        Variables { values, path }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(path) = &self.path {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            let contents = self
+                .values
+                .iter()
+                .map(|(name, value)| format!("{} = {}\n", name, value))
+                .collect::<String>();
+            fs::write(path, contents)?;
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.values.get(name)
+    }
+
+    pub fn set(&mut self, name: &str, value: Value) -> Result<()> {
+        if !is_valid_name(name) {
+            bail!("Variable names must start with a letter and contain only letters, digits or _");
+        }
+        self.values.insert(String::from(name), value);
+        // Persisting is best effort, a read-only data dir shouldn't stop the calculator working
+        self.save().ok();
+        Ok(())
+    }
+
+    pub fn set_ans(&mut self, value: Value) -> Result<()> {
+        self.set(ANS, value)
+    }
+
+    pub fn references(&self, input: &str, name: &str) -> bool {
+        input.split(',').any(|entry| entry.trim() == name)
+    }
+
+    /// Replaces every variable name in a (possibly comma separated) input with its value, a
+    /// list becomes all of its numbers
+    pub fn resolve(&self, input: &str) -> String {
+        input
+            .split(',')
+            .map(|entry| match self.get(entry.trim()) {
+                Some(value) => format!("{}", value),
+                None => String::from(entry.trim()),
+            })
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+}
+
+pub fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_alphabetic() => chars.all(|c| c.is_alphanumeric() || c == '_'),
+        _ => false,
+    }
+}
+
+/// Splits a formula output into the numeric and list fields that can be stored in a variable
+pub fn result_fields(output: &str) -> Vec<(String, Value)> {
+    output
+        .lines()
+        .filter_map(|line| match line.split_once(':') {
+            Some((label, value)) => {
+                Value::parse(value).map(|value| (String::from(label.trim()), value))
+            }
+            None => line
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .map(|value| (String::from("Result"), Value::Number(value))),
+        })
+        .collect()
+}
+
+/// The number on a formula output's `label` line, for checking results in tests
+#[cfg(test)]
+pub fn number_field(output: &str, label: &str) -> f64 {
+    match result_fields(output)
+        .into_iter()
+        .find(|(name, _)| name == label)
+    {
+        Some((_, Value::Number(x))) => x,
+        _ => panic!("no {} in {}", label, output),
+    }
+}